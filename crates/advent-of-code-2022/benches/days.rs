@@ -0,0 +1,238 @@
+//! Benchmarks the parsing and both parts of every registered day against its real input.
+//!
+//! `cargo bench --bench days -- --save-baseline main` keeps the medians in `profiling/baselines/main.toml`, which a
+//! later `cargo bench --bench days -- --baseline main` compares against, failing when a benchmark got slower than
+//! `--threshold` allows.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::shared::baseline::Baseline;
+use advent_of_code_2022::shared::context::SolveContext;
+use advent_of_code_2022::shared::day::PuzzleId;
+use advent_of_code_2022::shared::input::DataDirs;
+use advent_of_code_2022::shared::runner::{Part, Solver};
+use advent_of_code_2022::shared::summary::parse_duration;
+use clap::Parser;
+
+/// Benchmarks every registered day against its input.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Only benchmarks the days whose name contains this, like `day15` or `2022/day1`.
+    filter: Option<String>,
+
+    /// How many times each day is solved, at most.
+    #[arg(long, default_value = "10")]
+    samples: NonZeroUsize,
+
+    /// Stops solving a day again once this much time went by, like `5s`.
+    #[arg(long, default_value = "5s", value_parser = parse_duration)]
+    max_time: Duration,
+
+    /// Saves the medians as the baseline NAME.
+    #[arg(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compares the medians with the baseline NAME, failing when a benchmark regressed.
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// How many percent slower than its baseline a benchmark may get.
+    #[arg(long, default_value = "10")]
+    threshold: u32,
+
+    /// Where the baselines are kept, `profiling/baselines` in the repository by default.
+    #[arg(long, value_name = "DIR")]
+    baselines: Option<PathBuf>,
+
+    /// Passed by `cargo bench`, which runs every benchmark anyway.
+    #[arg(long, hide = true)]
+    bench: bool,
+}
+
+impl Args {
+    fn baseline_path(&self, name: &str) -> PathBuf {
+        let dir = self.baselines.clone().unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .ancestors()
+                .nth(2)
+                .expect("the crate is in `crates/`")
+                .join("profiling")
+                .join("baselines")
+        });
+
+        dir.join(format!("{}.toml", name))
+    }
+}
+
+fn main() -> ExitCode {
+    // test runners like `cargo nextest` start every target of `--all-targets` with the libtest flags
+    // `--list --format terse` to collect its tests, of which there are none here
+    if env::args().any(|arg| arg == "--list") {
+        return ExitCode::SUCCESS;
+    }
+
+    let args = Args::parse();
+
+    let dirs = match DataDirs::discover() {
+        Ok(dirs) => dirs,
+        Err(error) => {
+            eprintln!("No data directories: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut current = Baseline::default();
+    let mut failed = false;
+
+    println!(
+        "{:<24} {:>12} {:>12} {:>12} {:>8}",
+        "benchmark", "median", "min", "max", "samples"
+    );
+
+    for (&id, solver) in &registry() {
+        let name = format!("{}/day{}", id.year, id.day);
+
+        if args
+            .filter
+            .as_ref()
+            .is_some_and(|filter| !name.contains(filter.as_str()))
+        {
+            continue;
+        }
+
+        let input = match dirs.read(&id.path("inputs")) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}: {}", name, error);
+                failed = true;
+
+                continue;
+            },
+        };
+
+        let Some(stages) = sample(solver.as_ref(), id, &input, &args) else {
+            failed = true;
+
+            continue;
+        };
+
+        for (stage, mut timings) in stages {
+            timings.sort_unstable();
+
+            let benchmark = format!("{}/{}", name, stage);
+            let median = timings[timings.len() / 2];
+
+            println!(
+                "{:<24} {:>12.2?} {:>12.2?} {:>12.2?} {:>8}",
+                benchmark,
+                median,
+                timings[0],
+                timings[timings.len() - 1],
+                timings.len()
+            );
+
+            current.insert(&benchmark, median);
+        }
+    }
+
+    if let Some(ref name) = args.save_baseline {
+        let path = args.baseline_path(name);
+
+        match current.save(&path) {
+            Ok(()) => println!("\nSaved the baseline `{}` to \"{}\"", name, path.display()),
+            Err(error) => {
+                eprintln!("Could not save the baseline `{}`: {}", name, error);
+                failed = true;
+            },
+        }
+    }
+
+    if let Some(ref name) = args.baseline {
+        match Baseline::load(&args.baseline_path(name)) {
+            Ok(baseline) => {
+                let regressions = baseline.regressions(&current, args.threshold);
+
+                if regressions.is_empty() {
+                    println!(
+                        "\nNothing is more than {}% slower than the baseline `{}`",
+                        args.threshold, name
+                    );
+                } else {
+                    println!(
+                        "\nMore than {}% slower than the baseline `{}`:",
+                        args.threshold, name
+                    );
+
+                    for regression in regressions {
+                        println!("  {}", regression);
+                    }
+
+                    failed = true;
+                }
+            },
+            Err(error) => {
+                eprintln!("Could not load the baseline `{}`: {}", name, error);
+                failed = true;
+            },
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Solves the puzzle `id` against `input` as often as `args` allow, and returns how long each stage took every time:
+/// `parse`, `part_1` and `part_2`.
+///
+/// Returns [`None`] after reporting why when it cannot be solved.
+fn sample(
+    solver: &dyn Solver,
+    id: PuzzleId,
+    input: &str,
+    args: &Args,
+) -> Option<BTreeMap<&'static str, Vec<Duration>>> {
+    let context = SolveContext::default();
+    let start = Instant::now();
+
+    let mut stages = BTreeMap::<&'static str, Vec<Duration>>::new();
+
+    for _ in 0..args.samples.get() {
+        let outcome = solver.solve(id.day, input, &Part::ALL, &context);
+
+        if let Some((part, error)) = outcome.errors().next() {
+            match part {
+                Some(part) => eprintln!("{}, part {}: {}", id, part, error),
+                None => eprintln!("{}: {}", id, error),
+            }
+
+            return None;
+        }
+
+        stages.entry("parse").or_default().push(outcome.parse);
+
+        for part in outcome.parts.as_deref().unwrap_or_default() {
+            let stage = match part.part {
+                Part::One => "part_1",
+                Part::Two => "part_2",
+            };
+
+            stages.entry(stage).or_default().push(part.solve);
+        }
+
+        if start.elapsed() >= args.max_time {
+            break;
+        }
+    }
+
+    Some(stages)
+}
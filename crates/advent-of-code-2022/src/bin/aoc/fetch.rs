@@ -0,0 +1,56 @@
+use std::env;
+use std::process::ExitCode;
+
+use advent_of_code_2022::shared::day::{Day, PuzzleId, Year};
+use advent_of_code_2022::shared::fetch::{
+    BASE_URL, DEFAULT_INTERVAL, FetchError, Fetched, Fetcher, Session, UreqClient,
+};
+use advent_of_code_2022::shared::input::{Config, DataDirs};
+use advent_of_code_2022::shared::runner::DaySelection;
+
+/// A [`Fetcher`] with the session of the user, and the data directories to write to.
+pub fn fetcher() -> Result<(Fetcher<UreqClient>, DataDirs), FetchError> {
+    let cwd = env::current_dir().map_err(|source| FetchError::Io {
+        path: ".".into(),
+        source,
+    })?;
+
+    let config = Config::load(&cwd).map_err(FetchError::Data)?;
+    let session = Session::load(&config)?;
+    let dirs = DataDirs::discover().map_err(FetchError::Data)?;
+
+    let fetcher = Fetcher::new(UreqClient::default(), session, BASE_URL, DEFAULT_INTERVAL);
+
+    Ok((fetcher, dirs))
+}
+
+/// Downloads the inputs of every day of `year` in `selection`, stopping at the first failure.
+pub fn fetch(year: Year, selection: DaySelection) -> ExitCode {
+    let (mut fetcher, dirs) = match fetcher() {
+        Ok(fetcher) => fetcher,
+        Err(error) => {
+            eprintln!("Cannot fetch inputs: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    for day in (1..=25)
+        .filter_map(Day::try_new)
+        .filter(|&day| selection.contains(day))
+    {
+        match fetcher.fetch_input(PuzzleId::new(year, day), &dirs) {
+            Ok(Fetched::Cached(path)) => println!("Day {}: already at {}", day, path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {}: downloaded to {}", day, path.display());
+            },
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+
+    ExitCode::SUCCESS
+}
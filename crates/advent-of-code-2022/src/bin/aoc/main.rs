@@ -0,0 +1,361 @@
+mod fetch;
+mod new;
+mod profile;
+mod submit;
+mod watch;
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{self, BufWriter, Write, stderr, stdout};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::shared::answers::Answers;
+use advent_of_code_2022::shared::context::SolveContext;
+use advent_of_code_2022::shared::day::{DEFAULT_YEAR, Day, PuzzleId, Year};
+use advent_of_code_2022::shared::error::SolveError;
+use advent_of_code_2022::shared::input::InputSource;
+use advent_of_code_2022::shared::pool::run_parallel;
+use advent_of_code_2022::shared::report::{ReportFormat, has_failures, write_json, write_junit};
+use advent_of_code_2022::shared::runner::{self, DayOutcome, DaySelection, Part};
+use advent_of_code_2022::shared::summary::{
+    SortOrder, SummaryOptions, parse_duration, write_summary,
+};
+use advent_of_code_2022::shared::visualize::{FrameFormat, Target, Visualizer};
+use clap::{Args, Parser, Subcommand};
+
+use crate::profile::ProfileArgs;
+
+/// Runs Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves one or more days against their puzzle input.
+    Run(RunArgs),
+    /// Downloads the puzzle input of one or more days, skipping those already downloaded.
+    Fetch {
+        /// The day (`7`) or range of days (`1..=25`) to download.
+        days: DaySelection,
+
+        /// The year of the days.
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: Year,
+    },
+    /// Scaffolds a day from the template, with empty input and example files, and registers it.
+    New {
+        /// The day to scaffold.
+        day: Day,
+
+        /// The year of the day. Days of other years than the default go in modules and binaries named after it.
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: Year,
+    },
+    /// Solves a part and submits the answer, unless an earlier verdict rules it out.
+    Submit {
+        /// The day to submit.
+        day: Day,
+        /// The part to submit.
+        #[arg(value_name = "1|2")]
+        part: Part,
+
+        /// The year of the day.
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: Year,
+    },
+    /// Solves a single part again and again against its input, for a profiler to sample.
+    Profile(ProfileArgs),
+    /// Rebuilds a day and checks it against its examples and input whenever its source, input or examples change.
+    Watch {
+        /// The day to watch.
+        day: Day,
+
+        /// The year of the day.
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: Year,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day (`7`) or range of days (`1..=20`) to run.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Option<DaySelection>,
+
+    /// Runs every day.
+    #[arg(long)]
+    all: bool,
+
+    /// The year of the days.
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: Year,
+
+    /// Only runs the given part.
+    #[arg(long, value_name = "1|2")]
+    part: Option<Part>,
+
+    /// Orders the summary table by day, or slowest part first.
+    #[arg(long, value_name = "day|slowest", default_value = "day")]
+    sort: SortOrder,
+
+    /// Hides parts from the summary table that were solved faster than this, e.g. `100ms`.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    slower_than: Option<Duration>,
+
+    /// Reads the input from this file, or from stdin when `-`, instead of the data directories. Needs a single day.
+    #[arg(long, value_name = "PATH")]
+    input: Option<InputSource>,
+
+    /// Writes the solutions as text, or a `json` or `junit` XML report comparing them with the known answers.
+    #[arg(long, value_name = "text|json|junit", default_value = "text")]
+    format: ReportFormat,
+
+    /// Also writes a `json` or `junit` XML report, after the `.json` or `.xml` extension, to this file. May be repeated,
+    /// to write every report from a single run.
+    #[arg(long, value_name = "PATH", value_parser = parse_report)]
+    report: Vec<(ReportFormat, PathBuf)>,
+
+    /// Solves the parts on this many threads, reporting once all of them are done.
+    #[arg(long, short, value_name = "N")]
+    jobs: Option<NonZeroUsize>,
+
+    /// Animates the simulations in the `terminal`, or writes their frames to files in this directory.
+    #[arg(long, value_name = "terminal|DIR", conflicts_with = "jobs")]
+    visualize: Option<Target>,
+
+    /// Writes the frames to an image each, or to an asciicast per part.
+    #[arg(
+        long,
+        value_name = "png|ppm|cast",
+        default_value = "png",
+        requires = "visualize"
+    )]
+    frame_format: FrameFormat,
+
+    /// Keeps one frame out of this many.
+    #[arg(long, value_name = "N", default_value = "1", requires = "visualize")]
+    frame_every: NonZeroUsize,
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let selection = args.days.unwrap_or(DaySelection::All);
+
+    if args.input.is_some() && !matches!(selection, DaySelection::Single(_)) {
+        eprintln!("`--input` needs a single day, not {}", selection);
+
+        return ExitCode::FAILURE;
+    }
+
+    let source = args.input.clone().unwrap_or_default();
+
+    let parts = args
+        .part
+        .as_ref()
+        .map_or(&Part::ALL[..], std::slice::from_ref);
+
+    let registry = registry();
+
+    let mut lock = stdout().lock();
+
+    let options = SummaryOptions {
+        sort: args.sort,
+        slower_than: args.slower_than,
+    };
+
+    // reports compare with the known answers, which are for the inputs in the data directories only
+    let reports = args.format != ReportFormat::Text || !args.report.is_empty();
+
+    let answers = if reports && source == InputSource::DataDirs {
+        Answers::load(args.year).unwrap_or_else(|error| {
+            eprintln!("No known answers: {}", error);
+
+            Answers::default()
+        })
+    } else {
+        Answers::default()
+    };
+
+    let mut sink = io::sink();
+
+    // reports are a single document, so the solutions are not written as they come
+    let progress: &mut dyn Write = if args.format == ReportFormat::Text {
+        &mut lock
+    } else {
+        &mut sink
+    };
+
+    // frames go to a sink of their own per part, which is why `--visualize` conflicts with `--jobs`: parts solved side by
+    // side would fight over the terminal
+    let context = args
+        .visualize
+        .clone()
+        .map_or_else(SolveContext::default, |target| {
+            SolveContext::default().with_visualizer(Visualizer {
+                target,
+                format: args.frame_format,
+                every: args.frame_every,
+            })
+        });
+
+    let outcomes = match args.jobs {
+        Some(jobs) => run_parallel(
+            &registry, args.year, selection, parts, &source, jobs, progress,
+        ),
+        None => runner::run(
+            &registry, args.year, selection, parts, &source, &context, progress,
+        ),
+    };
+
+    let result = outcomes.and_then(|outcomes| {
+        if !outcomes.is_empty() {
+            write_reports(args, &outcomes, &answers, &options, &mut lock)?;
+        }
+
+        Ok(outcomes)
+    });
+
+    match result {
+        Ok(outcomes) if outcomes.is_empty() => {
+            eprintln!("No solutions found for {} of {}", selection, args.year);
+
+            ExitCode::FAILURE
+        },
+        Ok(outcomes) => {
+            let mut stderr = stderr().lock();
+
+            let failed = has_failures(&outcomes, &answers);
+
+            for outcome in &outcomes {
+                if let Err(error) = outcome.write_errors(&mut stderr) {
+                    eprintln!("Failed to write errors: {}", error);
+                }
+            }
+
+            let missing = DaySelection::spanning(
+                outcomes
+                    .iter()
+                    .filter(|outcome| matches!(outcome.parts, Err(SolveError::NotFound { .. })))
+                    .map(|outcome| outcome.day),
+            );
+
+            // `aoc fetch` skips the inputs in between which are there already
+            if let Some(days) = missing.as_ref().and_then(DaySelection::argument)
+                && source == InputSource::DataDirs
+            {
+                eprintln!(
+                    "Missing inputs can be downloaded with `aoc fetch {} --year {}`",
+                    days, args.year
+                );
+            }
+
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        },
+        Err(error) => {
+            eprintln!("Failed to write output: {}", error);
+
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Parses the path of a `--report`, the format of which its extension tells.
+fn parse_report(path: &str) -> Result<(ReportFormat, PathBuf), String> {
+    let path = PathBuf::from(path);
+
+    match path.extension().and_then(OsStr::to_str) {
+        Some("json") => Ok((ReportFormat::Json, path)),
+        Some("xml") => Ok((ReportFormat::Junit, path)),
+        Some(_) | None => Err("expecting a `.json` or `.xml` file".to_owned()),
+    }
+}
+
+/// Writes the summary or report of `outcomes` that `args` asks for to `out`, then every `--report` to its file.
+fn write_reports(
+    args: &RunArgs,
+    outcomes: &[DayOutcome],
+    answers: &Answers,
+    options: &SummaryOptions,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    write_report(args.format, args.year, outcomes, answers, options, out)?;
+
+    for &(format, ref path) in &args.report {
+        let written = File::create(path).and_then(|file| {
+            let mut file = BufWriter::new(file);
+
+            write_report(format, args.year, outcomes, answers, options, &mut file)?;
+
+            file.flush()
+        });
+
+        written.map_err(|error| {
+            io::Error::new(
+                error.kind(),
+                format!("could not write \"{}\": {}", path.display(), error),
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Writes `outcomes` of `year` to `out` in `format`, comparing them with `answers` unless in a text summary.
+fn write_report(
+    format: ReportFormat,
+    year: Year,
+    outcomes: &[DayOutcome],
+    answers: &Answers,
+    options: &SummaryOptions,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    match format {
+        ReportFormat::Text => {
+            writeln!(out)?;
+            write_summary(outcomes, options, out)
+        },
+        ReportFormat::Json => write_json(year, outcomes, answers, out),
+        ReportFormat::Junit => write_junit(year, outcomes, answers, out),
+    }
+}
+
+fn new(id: PuzzleId) -> ExitCode {
+    match new::new_day(id) {
+        Ok(created) => {
+            for path in created {
+                println!("Wrote {}", path.display());
+            }
+
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("Could not scaffold {}: {}", id, error);
+
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(ref args) => run(args),
+        Command::Fetch { days, year } => fetch::fetch(year, days),
+        Command::New { day, year } => new(PuzzleId::new(year, day)),
+        Command::Profile(ref args) => profile::profile(args),
+        Command::Submit { day, part, year } => submit::submit_part(PuzzleId::new(year, day), part),
+        Command::Watch { day, year } => watch::watch(PuzzleId::new(year, day)),
+    }
+}
@@ -0,0 +1,521 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write as _};
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2022::shared::day::{DEFAULT_YEAR, Day, PuzzleId};
+use advent_of_code_2022::shared::error::SolveError;
+use advent_of_code_2022::shared::input::DataDirs;
+
+const TEMPLATE: &str = include_str!("../../template/xx.rs");
+
+/// The invocation of [`solution!`](advent_of_code_2022::solution!) in the template, for the default year.
+const TEMPLATE_SOLUTION: &str = "crate::solution!();";
+
+/// Scaffolds the puzzle `id`: its solution module from the template with a binary to run it, empty input and example
+/// files, a manifest listing the example, a section in `answers.toml` to fill in, and its entry in the registry.
+///
+/// The days of the default year are `src/days/dayNN.rs` and `src/bin/NN.rs`. Those of other years have the year in
+/// their paths, `src/days/y<year>/dayNN.rs` declared by `src/days/y<year>.rs`, and `src/bin/<year>-NN.rs`, so that the
+/// same day of every year fits side by side.
+///
+/// Nothing is written when any of the files already exists, and whatever was written is undone when a later step
+/// fails.
+///
+/// Returns every file that was created or changed.
+pub fn new_day(id: PuzzleId) -> Result<Vec<PathBuf>, NewError> {
+    let data = DataDirs::discover().map_err(NewError::Data)?;
+    let data = data.first_existing().map_err(NewError::Data)?;
+
+    scaffold(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), data, id)
+}
+
+/// Scaffolds the puzzle `id` in the sources `src` and the data directory `data`, as [`new_day`] does.
+fn scaffold(src: &Path, data: &Path, id: PuzzleId) -> Result<Vec<PathBuf>, NewError> {
+    let day = id.day;
+    let module = format!("day{}", day);
+
+    // the module of the year, in which the day is declared, for any but the default year
+    let year = (id.year != DEFAULT_YEAR).then(|| format!("y{}", id.year));
+
+    let path = year
+        .as_ref()
+        .map_or_else(|| module.clone(), |year| format!("{}::{}", year, module));
+
+    let solution = solution_path(src, id);
+    let binary = src.join("bin").join(format!("{}.rs", binary_name(id)));
+
+    let input = data.join(id.path("inputs"));
+    let example = data.join(id.path("examples"));
+    let manifest = example.with_extension("toml");
+
+    if let Some(existing) = [&solution, &binary, &input, &example, &manifest]
+        .into_iter()
+        .find(|path| path.exists())
+    {
+        return Err(NewError::Exists(existing.clone()));
+    }
+
+    // every edit is worked out before anything is written, so that most failures leave nothing to undo
+    let days = src.join("days.rs");
+    let registered = register(
+        &days,
+        &read(&days)?,
+        &path,
+        year.as_deref().unwrap_or(&module),
+    )?;
+
+    let year_module = match year {
+        Some(ref year) => {
+            let path = src.join("days").join(format!("{}.rs", year));
+            let contents = read_or_empty(&path)?;
+
+            Some((path, declare(&contents, &module)))
+        },
+        None => None,
+    };
+
+    let answers = data.join(id.year.path("answers.toml"));
+    let placeholders = add_placeholder_answers(&read_or_empty(&answers)?, day);
+
+    let template = if id.year == DEFAULT_YEAR {
+        TEMPLATE.to_owned()
+    } else {
+        TEMPLATE.replace(
+            TEMPLATE_SOLUTION,
+            &format!("crate::solution!({});", id.year),
+        )
+    };
+
+    let mut changes = Changes::default();
+
+    let result = changes
+        .create(&solution, &template)
+        .and_then(|()| changes.create(&binary, &binary_shim(&path)))
+        .and_then(|()| changes.create(&input, ""))
+        .and_then(|()| changes.create(&example, ""))
+        .and_then(|()| changes.create(&manifest, &manifest_placeholder(day)))
+        .and_then(|()| {
+            year_module.map_or(Ok(()), |(ref path, ref contents)| {
+                changes.edit(path, contents)
+            })
+        })
+        .and_then(|()| changes.edit(&days, &registered))
+        .and_then(|()| {
+            placeholders.map_or(Ok(()), |placeholders| changes.edit(&answers, &placeholders))
+        });
+
+    match result {
+        Ok(()) => Ok(changes.paths()),
+        Err(error) => {
+            changes.roll_back();
+
+            Err(error)
+        },
+    }
+}
+
+/// The solution module of the puzzle `id` in the sources `src`, where [`new_day`] puts it.
+pub fn solution_path(src: &Path, id: PuzzleId) -> PathBuf {
+    let days = src.join("days");
+    let file = format!("day{}.rs", id.day);
+
+    if id.year == DEFAULT_YEAR {
+        days.join(file)
+    } else {
+        days.join(format!("y{}", id.year)).join(file)
+    }
+}
+
+/// The name of the binary of the puzzle `id`, like `07`, or `2021-07` for other years than the default.
+pub fn binary_name(id: PuzzleId) -> String {
+    if id.year == DEFAULT_YEAR {
+        id.day.to_string()
+    } else {
+        format!("{}-{}", id.year, id.day)
+    }
+}
+
+/// The files a scaffold wrote so far, so that it can be undone.
+#[derive(Default)]
+struct Changes {
+    created: Vec<PathBuf>,
+    /// The files which were changed, with their contents before.
+    edited: Vec<(PathBuf, Option<String>)>,
+}
+
+impl Changes {
+    /// Creates the file `path` with `contents`, and the directories it is in when needed.
+    fn create(&mut self, path: &Path, contents: &str) -> Result<(), NewError> {
+        let io = |source| NewError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+
+        // `create_new` rather than the check in `scaffold`, which races with anything else writing here
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(io)?;
+
+        self.created.push(path.to_path_buf());
+
+        file.write_all(contents.as_bytes()).map_err(io)
+    }
+
+    /// Replaces the contents of the file `path`, or creates it, with `contents`.
+    fn edit(&mut self, path: &Path, contents: &str) -> Result<(), NewError> {
+        let before = fs::read_to_string(path).ok();
+
+        fs::write(path, contents).map_err(|source| NewError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        self.edited.push((path.to_path_buf(), before));
+
+        Ok(())
+    }
+
+    /// Every file that was created or changed.
+    fn paths(self) -> Vec<PathBuf> {
+        self.created
+            .into_iter()
+            .chain(self.edited.into_iter().map(|(path, _)| path))
+            .collect()
+    }
+
+    /// Removes the files that were created, and restores those that were changed.
+    fn roll_back(self) {
+        let restored = self
+            .created
+            .iter()
+            .map(|path| (path, fs::remove_file(path)))
+            .chain(self.edited.iter().map(|&(ref path, ref before)| {
+                let result = match *before {
+                    Some(ref contents) => fs::write(path, contents),
+                    None => fs::remove_file(path),
+                };
+
+                (path, result)
+            }));
+
+        for (path, result) in restored {
+            if let Err(error) = result {
+                eprintln!("Could not undo \"{}\": {}", path.display(), error);
+            }
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, NewError> {
+    fs::read_to_string(path).map_err(|source| NewError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Like [`read`], but a file that does not exist yet is empty.
+fn read_or_empty(path: &Path) -> Result<String, NewError> {
+    match read(path) {
+        Err(NewError::Io { ref source, .. }) if source.kind() == ErrorKind::NotFound => {
+            Ok(String::new())
+        },
+        result => result,
+    }
+}
+
+/// The `contents` of `days.rs` at `path` with the solution of the day at `path` from there, like `day21` or
+/// `y2021::day03`, added to the end of the `registry`, and `module` declared after the other modules unless it already
+/// is.
+fn register(
+    path: &Path,
+    contents: &str,
+    module_path: &str,
+    module: &str,
+) -> Result<String, NewError> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    let solution = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("(*"))
+        .ok_or_else(|| NewError::NoRegistry(path.to_path_buf()))?;
+
+    let entry = format!(
+        "        (*{path}::PUZZLE, Box::new({path}::Solution {{}})),",
+        path = module_path
+    );
+    lines.insert(solution + 1, &entry);
+
+    Ok(declare(&format!("{}\n", lines.join("\n")), module))
+}
+
+/// The `contents` of a module with `module` declared after the other modules, unless it already is.
+fn declare(contents: &str, module: &str) -> String {
+    let declaration = format!("pub mod {};", module);
+
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    if lines.contains(&declaration.as_str()) {
+        return contents.to_owned();
+    }
+
+    let after = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod "))
+        .map_or(0, |index| index + 1);
+
+    lines.insert(after, &declaration);
+
+    format!("{}\n", lines.join("\n"))
+}
+
+/// The binary of the day at `path` from `days`, which only runs its solution module.
+fn binary_shim(path: &str) -> String {
+    format!(
+        "use std::process::ExitCode;\n\nuse advent_of_code_2022::days::{path}::{{PUZZLE, Solution}};\nuse advent_of_code_2022::shared::solution::day_main;\n\nfn main() -> ExitCode {{\n    day_main(*PUZZLE, &Solution {{}})\n}}\n",
+        path = path
+    )
+}
+
+/// The manifest of `day`, listing its example without answers.
+fn manifest_placeholder(day: Day) -> String {
+    format!(
+        "# The examples of day {day}, with their known answers.\n\n[[examples]]\nfile = \"{day}.txt\"\n# part_1 =\n# part_2 =\n",
+        day = day
+    )
+}
+
+/// The `contents` of `answers.toml` with an empty section for `day` appended, unless it already has one.
+fn add_placeholder_answers(contents: &str, day: Day) -> Option<String> {
+    let prefix = format!("[{}.", day);
+
+    if contents.lines().any(|line| line.starts_with(&prefix)) {
+        return None;
+    }
+
+    Some(format!(
+        "{}\n[{day}.inputs]\n# part_1 =\n# part_2 =\n",
+        contents,
+        day = day
+    ))
+}
+
+/// Why a day could not be scaffolded.
+#[derive(Debug)]
+pub enum NewError {
+    /// The file exists already, and is left alone.
+    Exists(PathBuf),
+    /// There is no data directory to put the input in.
+    Data(SolveError),
+    /// The file declares no `registry` of solutions to add the day to.
+    NoRegistry(PathBuf),
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error for NewError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            NewError::Exists(_) | NewError::NoRegistry(_) => None,
+            NewError::Data(ref source) => Some(source),
+            NewError::Io { ref source, .. } => Some(source),
+        }
+    }
+}
+
+impl Display for NewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            NewError::Exists(ref path) => write!(f, "\"{}\" already exists", path.display()),
+            NewError::Data(ref source) => write!(f, "no data directory: {}", source),
+            NewError::NoRegistry(ref path) => {
+                write!(
+                    f,
+                    "\"{}\" has no `registry` to add the day to",
+                    path.display()
+                )
+            },
+            NewError::Io {
+                ref path,
+                ref source,
+            } => write!(f, "could not write \"{}\": {}", path.display(), source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::{env, fs};
+
+    use advent_of_code_2022::shared::day::PuzzleId;
+    use advent_of_code_2022::{day, year};
+    use pretty_assertions::assert_eq;
+
+    use crate::new::{NewError, scaffold};
+
+    const DAYS: &str = "pub mod day01;\n\n\
+                        pub fn registry() -> Registry {\n    \
+                        let solutions: Vec<(PuzzleId, Box<dyn Solver>)> = vec![\n        \
+                        (*day01::PUZZLE, Box::new(day01::Solution {})),\n    \
+                        ];\n\n    \
+                        solutions.into_iter().collect()\n\
+                        }\n";
+
+    /// Sources with a single day, and a data directory with answers to it, in a directory of their own.
+    fn tree(name: &str) -> (PathBuf, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc-new-{}-{}", name, std::process::id()));
+
+        let src = dir.join("src");
+        let data = dir.join("data");
+
+        fs::create_dir_all(src.join("days")).unwrap();
+        fs::create_dir_all(src.join("bin")).unwrap();
+        fs::create_dir_all(data.join("2022")).unwrap();
+
+        fs::write(src.join("days.rs"), DAYS).unwrap();
+        fs::write(data.join("2022/answers.toml"), "[1.inputs]\npart_1 = 1\n").unwrap();
+
+        (src, data)
+    }
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn scaffolds_and_registers() {
+        let (src, data) = tree("scaffold");
+
+        let changed = scaffold(&src, &data, PuzzleId::new(year!(2022), day!(21))).unwrap();
+
+        assert_eq!(7, changed.len(), "every file is listed: {:?}", changed);
+        assert!(
+            read(&src.join("days/day21.rs")).contains("crate::solution!();"),
+            "the default year is left out"
+        );
+        assert!(
+            read(&src.join("bin/21.rs")).contains("days::day21::{PUZZLE, Solution}"),
+            "the binary runs the module"
+        );
+        assert_eq!(
+            DAYS.replace("pub mod day01;\n", "pub mod day01;\npub mod day21;\n")
+                .replace(
+                    "day01::Solution {})),\n",
+                    "day01::Solution {})),\n        (*day21::PUZZLE, Box::new(day21::Solution {})),\n"
+                ),
+            read(&src.join("days.rs"))
+        );
+        assert_eq!(
+            "[1.inputs]\npart_1 = 1\n\n[21.inputs]\n# part_1 =\n# part_2 =\n",
+            read(&data.join("2022/answers.toml"))
+        );
+        assert_eq!("", read(&data.join("2022/inputs/21.txt")));
+        assert!(
+            read(&data.join("2022/examples/21.toml")).contains("file = \"21.txt\""),
+            "the manifest lists the example"
+        );
+
+        let again = scaffold(&src, &data, PuzzleId::new(year!(2022), day!(21)));
+
+        fs::remove_dir_all(src.parent().unwrap()).unwrap();
+
+        assert!(
+            matches!(again, Err(NewError::Exists(_))),
+            "a day is only scaffolded once"
+        );
+    }
+
+    #[test]
+    fn scaffolds_other_years() {
+        let (src, data) = tree("year");
+
+        // day 1 of the default year is there already
+        fs::write(src.join("days/day01.rs"), "").unwrap();
+        fs::write(src.join("bin/01.rs"), "").unwrap();
+
+        let changed = scaffold(&src, &data, PuzzleId::new(year!(2021), day!(1))).unwrap();
+
+        scaffold(&src, &data, PuzzleId::new(year!(2021), day!(3))).unwrap();
+
+        let solution = read(&src.join("days/y2021/day01.rs"));
+        let binary = read(&src.join("bin/2021-01.rs"));
+        let year_module = read(&src.join("days/y2021.rs"));
+        let days = read(&src.join("days.rs"));
+        let answers = read(&data.join("2021/answers.toml"));
+        let input = data.join("2021/inputs/01.txt").is_file();
+
+        fs::remove_dir_all(src.parent().unwrap()).unwrap();
+
+        assert_eq!(
+            8,
+            changed.len(),
+            "the module of the year is listed: {:?}",
+            changed
+        );
+        assert!(
+            solution.contains("crate::solution!(2021);"),
+            "the year is passed on"
+        );
+        assert!(
+            binary.contains("days::y2021::day01::{PUZZLE, Solution}"),
+            "the binary runs the module of its year"
+        );
+        assert_eq!("pub mod day01;\npub mod day03;\n", year_module);
+        assert_eq!(
+            DAYS.replace("pub mod day01;\n", "pub mod day01;\npub mod y2021;\n")
+                .replace(
+                    "day01::Solution {})),\n",
+                    "day01::Solution {})),\n        \
+                     (*y2021::day01::PUZZLE, Box::new(y2021::day01::Solution {})),\n        \
+                     (*y2021::day03::PUZZLE, Box::new(y2021::day03::Solution {})),\n"
+                ),
+            days
+        );
+        assert_eq!(
+            "\n[01.inputs]\n# part_1 =\n# part_2 =\n\n[03.inputs]\n# part_1 =\n# part_2 =\n",
+            answers
+        );
+        assert!(input, "the input is in the data of its year");
+    }
+
+    #[test]
+    fn rolls_back_on_failure() {
+        let (src, data) = tree("roll-back");
+
+        // a file where the examples should go, which fails once the solution, binary and input are written
+        fs::create_dir_all(data.join("2022/inputs")).unwrap();
+        fs::write(data.join("2022/examples"), "").unwrap();
+
+        let result = scaffold(&src, &data, PuzzleId::new(year!(2022), day!(21)));
+
+        let left = [
+            src.join("days/day21.rs"),
+            src.join("bin/21.rs"),
+            data.join("2022/inputs/21.txt"),
+        ]
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect::<Vec<_>>();
+
+        let days = read(&src.join("days.rs"));
+
+        fs::remove_dir_all(src.parent().unwrap()).unwrap();
+
+        assert!(
+            matches!(result, Err(NewError::Io { .. })),
+            "the examples cannot be written"
+        );
+        assert_eq!(Vec::<PathBuf>::new(), left);
+        assert_eq!(DAYS, days);
+    }
+}
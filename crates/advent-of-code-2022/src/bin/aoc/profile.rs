@@ -0,0 +1,196 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write as _};
+use std::num::NonZeroUsize;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::shared::PartSolution;
+use advent_of_code_2022::shared::context::SolveContext;
+use advent_of_code_2022::shared::day::{DEFAULT_YEAR, Day, PuzzleId, Year};
+use advent_of_code_2022::shared::input::DataDirs;
+use advent_of_code_2022::shared::runner::Part;
+use advent_of_code_2022::shared::summary::parse_duration;
+use clap::Args;
+
+/// How long a part is solved again for when neither `--iterations` nor `--duration` is given.
+const DEFAULT_DURATION: Duration = Duration::from_secs(10);
+
+#[derive(Args)]
+pub struct ProfileArgs {
+    /// The day to profile.
+    day: Day,
+    /// The part to profile.
+    #[arg(value_name = "1|2")]
+    part: Part,
+
+    /// The year of the day.
+    #[arg(long, default_value_t = DEFAULT_YEAR)]
+    year: Year,
+
+    /// Stops after solving the part this many times.
+    #[arg(long, value_name = "N")]
+    iterations: Option<NonZeroUsize>,
+
+    /// Stops once this much time went by, like `10s`. Defaults to 10 seconds without `--iterations`.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    duration: Option<Duration>,
+
+    /// Writes how long every iteration took to `profiling/<year>-<day>-<part>.csv` in the repository.
+    #[arg(long)]
+    timings: bool,
+}
+
+impl ProfileArgs {
+    /// Where the timings of the puzzle `id` go, if anywhere.
+    fn timings_path(&self, id: PuzzleId) -> Option<PathBuf> {
+        self.timings.then(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .ancestors()
+                .nth(2)
+                .expect("the crate is in `crates/`")
+                .join("profiling")
+                .join(format!("{}-{}-{}.csv", id.year, id.day, self.part))
+        })
+    }
+}
+
+/// Solves a single part against its pre-loaded input in a tight loop, for a profiler such as `perf` to sample.
+///
+/// Nothing but the solution is written to stdout, so that successive profiles are comparable; the number of iterations,
+/// which varies with `--duration`, and the timings go to stderr.
+pub fn profile(args: &ProfileArgs) -> ExitCode {
+    let id = PuzzleId::new(args.year, args.day);
+
+    let registry = registry();
+
+    let Some(solver) = registry.get(&id) else {
+        eprintln!("No solution for {}", id);
+
+        return ExitCode::FAILURE;
+    };
+
+    let input = match DataDirs::discover().and_then(|dirs| dirs.read(&id.path("inputs"))) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}: {}", id, error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let duration = match (args.iterations, args.duration) {
+        (None, None) => Some(DEFAULT_DURATION),
+        (_, duration) => duration,
+    };
+
+    let mut first = None::<PartSolution>;
+    let mut timings = Vec::new();
+    let mut failure = None;
+
+    let start = Instant::now();
+
+    let result = solver.repeat(
+        id.day,
+        &input,
+        args.part,
+        &SolveContext::default(),
+        &mut |solution, solve| {
+            timings.push(solve);
+
+            match (solution, first.as_ref()) {
+                (Err(error), _) => {
+                    failure = Some(error.to_string());
+
+                    return ControlFlow::Break(());
+                },
+                (Ok(solution), Some(first)) if solution != *first => {
+                    failure = Some(format!(
+                        "iteration {} solved {}, not {}",
+                        timings.len(),
+                        solution,
+                        first
+                    ));
+
+                    return ControlFlow::Break(());
+                },
+                (Ok(solution), None) => first = Some(solution),
+                (Ok(_), Some(_)) => {},
+            }
+
+            let done = args
+                .iterations
+                .is_some_and(|iterations| timings.len() >= iterations.get())
+                || duration.is_some_and(|duration| start.elapsed() >= duration);
+
+            if done {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+
+    if let Err(error) = result {
+        eprintln!("{}: {}", id, error);
+
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(error) = failure {
+        eprintln!("{}, part {}: {}", id, args.part, error);
+
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(solution) = first {
+        println!("{}, part {}: {}", id, args.part, solution);
+    }
+
+    eprintln!("{} iterations", timings.len());
+
+    if let Some(path) = args.timings_path(id)
+        && let Err(error) = write_timings(&path, &timings)
+    {
+        eprintln!(
+            "Could not write the timings to \"{}\": {}",
+            path.display(),
+            error
+        );
+
+        return ExitCode::FAILURE;
+    }
+
+    let mut sorted = timings;
+    sorted.sort_unstable();
+
+    if let (Some(min), Some(max)) = (sorted.first(), sorted.last()) {
+        eprintln!(
+            "median {:.2?}, min {:.2?}, max {:.2?}",
+            sorted[sorted.len() / 2],
+            min,
+            max
+        );
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Writes `timings` to `path` as CSV, one iteration per row, creating its directory when needed.
+fn write_timings(path: &Path, timings: &[Duration]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut out = BufWriter::new(File::create(path)?);
+
+    writeln!(out, "iteration,nanoseconds")?;
+
+    for (iteration, timing) in timings.iter().enumerate() {
+        writeln!(out, "{},{}", iteration + 1, timing.as_nanos())?;
+    }
+
+    out.flush()
+}
@@ -0,0 +1,86 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::shared::context::SolveContext;
+use advent_of_code_2022::shared::day::PuzzleId;
+use advent_of_code_2022::shared::input::InputSource;
+use advent_of_code_2022::shared::runner::{Part, solve_day};
+use advent_of_code_2022::shared::submit::{Judgement, Reply, Submitted, VerdictCache, submit};
+
+use crate::fetch::fetcher;
+
+/// Solves `part` of the puzzle `id` and submits the solution, unless an earlier verdict rules it out.
+pub fn submit_part(id: PuzzleId, part: Part) -> ExitCode {
+    let registry = registry();
+
+    let Some(solution) = registry.get(&id) else {
+        eprintln!("No solution for {}", id);
+
+        return ExitCode::FAILURE;
+    };
+
+    let outcome = solve_day(
+        id,
+        solution.as_ref(),
+        &[part],
+        &InputSource::DataDirs,
+        &SolveContext::default(),
+    );
+
+    let solution = match outcome
+        .parts
+        .map(|mut parts| parts.pop().map(|part| part.solution))
+    {
+        Ok(Some(Ok(solution))) => solution,
+        Ok(Some(Err(error))) | Err(error) => {
+            eprintln!("{}, part {}: {}", id, part, error);
+
+            return ExitCode::FAILURE;
+        },
+        Ok(None) => return ExitCode::FAILURE,
+    };
+
+    let (mut fetcher, dirs) = match fetcher() {
+        Ok(fetcher) => fetcher,
+        Err(error) => {
+            eprintln!("Cannot submit: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let result = VerdictCache::load(&dirs, id.year)
+        .and_then(|mut cache| submit(&mut fetcher, &mut cache, id, part, &solution));
+
+    match result {
+        Ok(Submitted::Rejected(rejection)) => {
+            println!("Not submitting {}: {}", solution, rejection);
+
+            ExitCode::FAILURE
+        },
+        Ok(Submitted::Replied(Reply::Judged(judgement))) => {
+            println!("{}: {}", solution, judgement);
+
+            if judgement == Judgement::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Submitted::Replied(Reply::Wait(wait))) => {
+            println!("Submitted too recently, try again in {:?}", wait);
+
+            ExitCode::FAILURE
+        },
+        Ok(Submitted::Replied(Reply::WrongLevel)) => {
+            println!("Part {} is solved already, or still locked", part);
+
+            ExitCode::FAILURE
+        },
+        Err(error) => {
+            eprintln!("Could not submit {}: {}", solution, error);
+
+            ExitCode::FAILURE
+        },
+    }
+}
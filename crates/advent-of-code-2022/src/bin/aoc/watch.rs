@@ -0,0 +1,140 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use advent_of_code_2022::shared::day::PuzzleId;
+use advent_of_code_2022::shared::input::DataDirs;
+use advent_of_code_2022::shared::watch::{CHECK_ARGUMENT, WatchedFiles};
+use notify::{Event, EventKind, RecursiveMode, Watcher as _};
+
+use crate::new::{binary_name, solution_path};
+
+/// How long to wait for more changes after the first one, as saving a file often takes several writes.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Watches the source, input and examples of the puzzle `id`, rebuilding and checking it on every change until
+/// interrupted.
+pub fn watch(id: PuzzleId) -> ExitCode {
+    let dirs = match DataDirs::discover() {
+        Ok(dirs) => dirs,
+        Err(error) => {
+            eprintln!("No data directories: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let solution = solution_path(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), id);
+
+    if !solution.is_file() {
+        eprintln!("No solution at \"{}\"", solution.display());
+
+        return ExitCode::FAILURE;
+    }
+
+    let files = WatchedFiles::new(id, solution, &dirs);
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            eprintln!("Cannot watch files: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    // data directories that do not exist are searched all the same, but there is nothing to watch in them
+    for dir in files.directories().into_iter().filter(|dir| dir.is_dir()) {
+        if let Err(error) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            eprintln!("Cannot watch \"{}\": {}", dir.display(), error);
+
+            return ExitCode::FAILURE;
+        }
+    }
+
+    check(id);
+
+    while let Ok(event) = receiver.recv() {
+        let Some(changed) = changed_file(&files, event) else {
+            continue;
+        };
+
+        while receiver.recv_timeout(SETTLE).is_ok() {}
+
+        println!();
+        println!("\"{}\" changed", changed.display());
+
+        check(id);
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// The watched file `event` changed, if any.
+fn changed_file(files: &WatchedFiles, event: notify::Result<Event>) -> Option<PathBuf> {
+    let event = match event {
+        Ok(event) => event,
+        Err(error) => {
+            eprintln!("Watching failed: {}", error);
+
+            return None;
+        },
+    };
+
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return None;
+    }
+
+    event.paths.into_iter().find(|path| files.contains(path))
+}
+
+/// Rebuilds the binary of the puzzle `id` and runs it against its examples and input.
+fn check(id: PuzzleId) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    println!("Checking {}", id);
+
+    let mut command = Command::new(cargo);
+
+    command
+        .args(["run", "--quiet", "--release", "--bin"])
+        .arg(binary_name(id))
+        .arg("--")
+        .arg(CHECK_ARGUMENT);
+
+    // started by `cargo run`, these describe this package, and would make build scripts that watch them rebuild
+    for (name, _) in env::vars_os() {
+        if name.to_str().is_some_and(is_package_variable) {
+            command.env_remove(name);
+        }
+    }
+
+    let status = command.status();
+
+    match status {
+        Ok(status) if status.success() => println!("{} is correct", id),
+        Ok(_) => println!("{} failed", id),
+        Err(error) => eprintln!("Could not run cargo: {}", error),
+    }
+}
+
+/// Whether `name` is one of the variables `cargo run` sets for the package of the binary it runs.
+fn is_package_variable(name: &str) -> bool {
+    name.starts_with("CARGO_PKG_")
+        || matches!(
+            name,
+            "CARGO_MANIFEST_DIR"
+                | "CARGO_MANIFEST_PATH"
+                | "CARGO_CRATE_NAME"
+                | "CARGO_BIN_NAME"
+                | "CARGO_PRIMARY_PACKAGE"
+        )
+}
@@ -1 +1,52 @@
+use crate::shared::day::PuzzleId;
+use crate::shared::runner::{Registry, Solver};
 
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+/// Every puzzle that has a solution, of any year.
+#[must_use]
+pub fn registry() -> Registry {
+    let solutions: Vec<(PuzzleId, Box<dyn Solver>)> = vec![
+        (*day01::PUZZLE, Box::new(day01::Solution {})),
+        (*day02::PUZZLE, Box::new(day02::Solution {})),
+        (*day03::PUZZLE, Box::new(day03::Solution {})),
+        (*day04::PUZZLE, Box::new(day04::Solution {})),
+        (*day05::PUZZLE, Box::new(day05::Solution {})),
+        (*day06::PUZZLE, Box::new(day06::Solution {})),
+        (*day07::PUZZLE, Box::new(day07::Solution {})),
+        (*day08::PUZZLE, Box::new(day08::Solution {})),
+        (*day09::PUZZLE, Box::new(day09::Solution {})),
+        (*day10::PUZZLE, Box::new(day10::Solution {})),
+        (*day11::PUZZLE, Box::new(day11::Solution {})),
+        (*day12::PUZZLE, Box::new(day12::Solution {})),
+        (*day13::PUZZLE, Box::new(day13::Solution {})),
+        (*day14::PUZZLE, Box::new(day14::Solution {})),
+        (*day15::PUZZLE, Box::new(day15::Solution {})),
+        (*day16::PUZZLE, Box::new(day16::Solution {})),
+        (*day17::PUZZLE, Box::new(day17::Solution {})),
+        (*day18::PUZZLE, Box::new(day18::Solution {})),
+        (*day19::PUZZLE, Box::new(day19::Solution {})),
+        (*day20::PUZZLE, Box::new(day20::Solution {})),
+    ];
+
+    solutions.into_iter().collect()
+}
@@ -0,0 +1,74 @@
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+fn parse_lines(input: &str) -> Vec<u32> {
+    let mut elves = Vec::new();
+
+    let mut current = 0;
+
+    // chain because the .lines() skips the final empty line
+    for line in input.lines().chain([""]) {
+        if line.is_empty() {
+            elves.push(current);
+
+            current = 0;
+            continue;
+        }
+
+        current += line.parse::<u32>().unwrap();
+    }
+
+    elves
+}
+
+fn get_max(elves: &[u32]) -> u32 {
+    elves.iter().copied().max().unwrap_or(0)
+}
+
+fn shift(current: u32, max: &mut [u32; 3]) {
+    let shift_after = max.iter().position(|v| v < &current);
+
+    let Some(index) = shift_after else { return };
+
+    let mut c_i = max.len() - 1;
+
+    while c_i > (index) {
+        max.swap(c_i, c_i - 1);
+
+        c_i -= 1;
+    }
+
+    max[index] = current;
+}
+
+fn get_top_3(elves: &[u32]) -> u32 {
+    let mut max = [0; 3];
+
+    for &elf in elves {
+        shift(elf, &mut max);
+    }
+
+    max.iter().sum()
+}
+
+impl Parts for Solution {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let max = get_max(input);
+
+        max.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let max = get_top_3(input);
+
+        max.into()
+    }
+}
@@ -0,0 +1,223 @@
+use crate::shared::context::SolveContext;
+use crate::shared::error::SolveError;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!();
+
+fn parse_lines(input: &str) -> Result<Vec<(Rps, Column)>, SolveError> {
+    let mut result = Vec::new();
+    for (line_index, line) in input.lines().map(str::trim).enumerate() {
+        let mut chars = line.chars();
+
+        let first: Rps = chars
+            .next()
+            .ok_or_else(|| SolveError::parse_at(line_index, 0, "expected a move, found nothing"))?
+            .try_into()
+            .map_err(|message| SolveError::parse_at(line_index, 0, message))?;
+
+        if chars.next() != Some(' ') {
+            return Err(SolveError::parse_at(line_index, 1, "expected a space"));
+        }
+
+        let second: Column = chars
+            .next()
+            .ok_or_else(|| SolveError::parse_at(line_index, 2, "expected a move, found nothing"))?
+            .try_into()
+            .map_err(|message| SolveError::parse_at(line_index, 2, message))?;
+
+        if chars.next().is_some() {
+            return Err(SolveError::parse_at(
+                line_index,
+                3,
+                "expected the end of the line",
+            ));
+        }
+
+        result.push((first, second));
+    }
+
+    Ok(result)
+}
+
+fn calculate_score_part_1(rounds: &[(Rps, Column)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(left, right)| score((left, right.into())))
+        .sum()
+}
+
+fn calculate_score_part_2(rounds: &[(Rps, Column)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(left, right)| answer_and_score((left, right.into())))
+        .sum()
+}
+
+/// The second column of the strategy guide, which means something else in each part.
+#[derive(Clone, Copy)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl TryFrom<char> for Column {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Column::X),
+            'Y' => Ok(Column::Y),
+            'Z' => Ok(Column::Z),
+            _ => Err(format!("expected `X`, `Y` or `Z`, found `{}`", c)),
+        }
+    }
+}
+
+enum Expected {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl From<Column> for Expected {
+    fn from(column: Column) -> Self {
+        match column {
+            Column::X => Expected::Lose,
+            Column::Y => Expected::Draw,
+            Column::Z => Expected::Win,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl From<Rps> for u32 {
+    fn from(rps: Rps) -> Self {
+        match rps {
+            Rps::Rock => 1,
+            Rps::Paper => 2,
+            Rps::Scissors => 3,
+        }
+    }
+}
+
+fn score((left, right): (Rps, Rps)) -> u32 {
+    let score = match left {
+        Rps::Rock => match right {
+            Rps::Rock => 3,
+            Rps::Paper => 6,
+            Rps::Scissors => 0,
+        },
+        Rps::Paper => match right {
+            Rps::Rock => 0,
+            Rps::Paper => 3,
+            Rps::Scissors => 6,
+        },
+        Rps::Scissors => match right {
+            Rps::Rock => 6,
+            Rps::Paper => 0,
+            Rps::Scissors => 3,
+        },
+    };
+
+    score + <Rps as Into<u32>>::into(right)
+}
+
+fn answer_and_score((left, right): (Rps, Expected)) -> u32 {
+    let right_should_play = match right {
+        Expected::Lose => match left {
+            Rps::Rock => Rps::Scissors,
+            Rps::Paper => Rps::Rock,
+            Rps::Scissors => Rps::Paper,
+        },
+        Expected::Draw => left,
+        Expected::Win => match left {
+            Rps::Rock => Rps::Paper,
+            Rps::Paper => Rps::Scissors,
+            Rps::Scissors => Rps::Rock,
+        },
+    };
+
+    score((left, right_should_play))
+}
+
+impl TryFrom<char> for Rps {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(Rps::Rock),
+            'B' => Ok(Rps::Paper),
+            'C' => Ok(Rps::Scissors),
+            _ => Err(format!("expected `A`, `B` or `C`, found `{}`", c)),
+        }
+    }
+}
+
+impl From<Column> for Rps {
+    fn from(column: Column) -> Self {
+        match column {
+            Column::X => Rps::Rock,
+            Column::Y => Rps::Paper,
+            Column::Z => Rps::Scissors,
+        }
+    }
+}
+
+impl TryParts for Solution {
+    type Input = Vec<(Rps, Column)>;
+
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
+
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let score = calculate_score_part_1(input);
+
+        Ok(score.into())
+    }
+
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let score = calculate_score_part_2(input);
+
+        Ok(score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use super::super::Solution;
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn malformed() {
+            assert!(
+                matches!(
+                    (Solution {}).try_parse("A Y\nB X\nQ Z"),
+                    Err(SolveError::Parse {
+                        line: 3,
+                        column: 1,
+                        ..
+                    })
+                ),
+                "`Q` is not a move"
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use hashbrown::HashSet;
+
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+fn parse_lines(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect()
+}
+
+fn char_to_value(c: char) -> u32 {
+    match c {
+        lower @ 'a'..='z' => u32::from(lower) - u32::from('a') + 1,
+        upper @ 'A'..='Z' => u32::from(upper) - u32::from('A') + 27,
+        _ => {
+            panic!("really?")
+        },
+    }
+}
+
+fn calculate_overlap(rucksacks: &[Vec<char>]) -> u32 {
+    let mut sum = 0;
+    for rucksack in rucksacks {
+        let (left, right) = rucksack.split_at(rucksack.len() / 2);
+
+        let left = left.iter().collect::<HashSet<_>>();
+        let right = right.iter().collect::<HashSet<_>>();
+
+        let c = **left.intersection(&right).next().unwrap();
+
+        let value = char_to_value(c);
+
+        sum += value;
+    }
+    sum
+}
+
+fn calculate_chunk_overlap(rucksacks: &[Vec<char>]) -> u32 {
+    let mut score = 0;
+
+    for chunk in rucksacks.chunks(3) {
+        let reduced = chunk
+            .iter()
+            .map(|rucksack| rucksack.iter().copied().collect::<HashSet<char>>())
+            .reduce(|acc, cur| acc.intersection(&cur).copied().collect::<_>());
+
+        let common_char = reduced
+            .and_then(|r| r.into_iter().next())
+            .unwrap_or_default();
+
+        score += char_to_value(common_char);
+    }
+
+    score
+}
+
+impl Parts for Solution {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let score = calculate_overlap(input);
+
+        score.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let score = calculate_chunk_overlap(input);
+
+        score.into()
+    }
+}
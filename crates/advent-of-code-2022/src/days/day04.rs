@@ -0,0 +1,80 @@
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+/// The first and last section an elf is assigned to.
+type Range = (u32, u32);
+
+fn parse_lines(input: &str) -> Vec<(Range, Range)> {
+    let mut result = Vec::new();
+
+    for line in input.lines().map(str::trim) {
+        let mut numbers = line
+            .split(&[',', '-'][..])
+            .map(|s| s.parse::<u32>().unwrap());
+
+        let first = numbers.next().unwrap();
+        let second = numbers.next().unwrap();
+        let third = numbers.next().unwrap();
+        let fourth = numbers.next().unwrap();
+
+        result.push(((first, second), (third, fourth)));
+    }
+
+    result
+}
+
+fn one_fully_contains_other(left: Range, right: Range) -> bool {
+    left.0 <= right.0 && left.1 >= right.1
+}
+
+fn one_overlaps_other(left: Range, right: Range) -> bool {
+    let range = left.0..=left.1;
+
+    range.contains(&right.0) || range.contains(&right.1)
+}
+
+fn count_fully_contained(lines: &[(Range, Range)]) -> u32 {
+    let mut count = 0;
+
+    for &(left, right) in lines {
+        if one_fully_contains_other(left, right) || one_fully_contains_other(right, left) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn count_any_overlap(lines: &[(Range, Range)]) -> u32 {
+    let mut count = 0;
+
+    for &(left, right) in lines {
+        if one_overlaps_other(left, right) || one_overlaps_other(right, left) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+impl Parts for Solution {
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let score = count_fully_contained(input);
+
+        score.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let score = count_any_overlap(input);
+
+        score.into()
+    }
+}
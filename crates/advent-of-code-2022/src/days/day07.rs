@@ -0,0 +1,230 @@
+#![expect(clippy::string_slice, reason = "Advent of Code is ASCII only")]
+
+use std::sync::{Arc, RwLock};
+
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+pub struct Directory {
+    name: String,
+    children: Vec<DirOrFile>,
+}
+
+impl std::fmt::Debug for Directory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "- {} (dir)", self.name)?;
+
+        for child in &self.children {
+            let s = format!("{:?}", child);
+
+            for line in s.lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+enum DirOrFile {
+    File { name: String, size: usize },
+    Directory(Arc<RwLock<Directory>>),
+}
+
+impl std::fmt::Debug for DirOrFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DirOrFile::Directory(ref d) => {
+                let borrowed_dir = d.read().unwrap();
+                write!(f, "{:?}", borrowed_dir)
+            },
+            DirOrFile::File { ref name, size } => {
+                writeln!(f, "- {} (file, size={})", name, size)
+            },
+        }
+    }
+}
+
+fn parse_lines(input: &str) -> Arc<RwLock<Directory>> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let root = Arc::new(RwLock::new(Directory {
+        name: "/".into(),
+        children: vec![],
+    }));
+
+    let mut history: Vec<Arc<RwLock<Directory>>> = Vec::new();
+
+    let mut current_line_index = 0;
+
+    while let Some(line) = lines.get(current_line_index) {
+        if line.starts_with("$ cd") {
+            // parse as command
+            match line[5..].trim() {
+                "/" => {
+                    history.push(Arc::clone(&root));
+                },
+                ".." => {
+                    history.pop();
+                },
+                dir_name => {
+                    let last = history.last().unwrap().read().unwrap();
+
+                    let dir_reference = Arc::clone(
+                        last.children
+                            .iter()
+                            .find_map(|c| match *c {
+                                DirOrFile::Directory(ref dir)
+                                    if dir.read().unwrap().name == dir_name =>
+                                {
+                                    Some(dir)
+                                },
+                                DirOrFile::File { .. } | DirOrFile::Directory(_) => None,
+                            })
+                            .unwrap(),
+                    );
+
+                    drop(last);
+
+                    history.push(dir_reference);
+                },
+            }
+
+            current_line_index += 1;
+            continue;
+        }
+
+        if line == &"$ ls" {
+            // parse out the set of lines as directory listing of the history.last()
+            let mut ls_index = current_line_index + 1;
+
+            let mut contents = vec![];
+
+            while let Some(&ls_line) = lines
+                .get(ls_index)
+                .filter(|&&ls_line| !ls_line.starts_with('$'))
+            {
+                let split = ls_line.split_whitespace().collect::<Vec<&str>>();
+
+                let left = split[0];
+                let right = split[1];
+
+                if left == "dir" {
+                    contents.push(DirOrFile::Directory(Arc::new(RwLock::new(Directory {
+                        name: right.into(),
+                        children: vec![],
+                    }))));
+                } else {
+                    // file
+                    contents.push(DirOrFile::File {
+                        name: right.into(),
+                        size: left.parse::<usize>().unwrap(),
+                    });
+                }
+
+                ls_index += 1;
+
+                let mut last = history.last().unwrap().write().unwrap();
+                last.children.append(&mut contents);
+            }
+
+            current_line_index = ls_index;
+            continue;
+        }
+
+        unreachable!()
+    }
+
+    root
+}
+
+fn dirs_smaller_than_100_000_r(sums: &mut Vec<usize>, dir: &Arc<RwLock<Directory>>) -> usize {
+    let mut sum = 0;
+
+    for child in &dir.read().unwrap().children {
+        match *child {
+            DirOrFile::File { size, .. } => sum += size,
+            DirOrFile::Directory(ref d) => sum += dirs_smaller_than_100_000_r(sums, d),
+        }
+    }
+
+    if sum <= 100_000 {
+        sums.push(sum);
+    }
+
+    sum
+}
+
+fn dirs_smaller_than_100_000(root: &Arc<RwLock<Directory>>) -> usize {
+    let mut sums = vec![];
+
+    dirs_smaller_than_100_000_r(&mut sums, root);
+
+    sums.into_iter().sum::<usize>()
+}
+
+fn sum_r(dir: &Arc<RwLock<Directory>>) -> usize {
+    let mut sum = 0;
+
+    for child in &dir.read().unwrap().children {
+        match *child {
+            DirOrFile::File { size, .. } => sum += size,
+            DirOrFile::Directory(ref d) => sum += sum_r(d),
+        }
+    }
+
+    sum
+}
+fn test(larger_than: &mut Vec<usize>, minimum_size: usize, dir: &Arc<RwLock<Directory>>) -> usize {
+    let mut sum = 0;
+
+    for child in &dir.read().unwrap().children {
+        match *child {
+            DirOrFile::File { size, .. } => sum += size,
+            DirOrFile::Directory(ref d) => sum += test(larger_than, minimum_size, d),
+        }
+    }
+    if sum >= minimum_size {
+        larger_than.push(sum);
+    }
+
+    sum
+}
+fn find_smallest_dir_to_delete(root: &Arc<RwLock<Directory>>) -> usize {
+    const TOTAL: usize = 70_000_000;
+    const MINIMUM_FREE: usize = 30_000_000;
+
+    let unused = TOTAL - sum_r(root);
+
+    let to_free = MINIMUM_FREE - unused;
+
+    let mut smaller_than_to_free = vec![];
+
+    test(&mut smaller_than_to_free, to_free, root);
+
+    smaller_than_to_free.sort_unstable();
+
+    smaller_than_to_free[0]
+}
+
+impl Parts for Solution {
+    type Input = Arc<RwLock<Directory>>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let sum_dirs_smaller_than_100_000 = dirs_smaller_than_100_000(input);
+
+        sum_dirs_smaller_than_100_000.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let smallest_dir_to_delete_size = find_smallest_dir_to_delete(input);
+
+        smallest_dir_to_delete_size.into()
+    }
+}
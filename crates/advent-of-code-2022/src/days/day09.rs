@@ -0,0 +1,230 @@
+#![expect(clippy::string_slice, reason = "Advent of Code is ASCII only")]
+
+use std::cmp::Ordering;
+
+use hashbrown::HashSet;
+
+use crate::shared::context::SolveContext;
+use crate::shared::visualize::{Cell, Color, Frame, Visualize};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn parse_lines(input: &str) -> Vec<(Direction, usize)> {
+    let mut directions = vec![];
+
+    for line in input.lines() {
+        let times = line[2..].parse::<usize>().unwrap();
+
+        let direction = match line.as_bytes()[0] {
+            b'U' => Direction::Up,
+            b'D' => Direction::Down,
+            b'L' => Direction::Left,
+            b'R' => Direction::Right,
+            _ => {
+                panic!()
+            },
+        };
+
+        directions.push((direction, times));
+    }
+
+    directions
+}
+
+/// The rope on the part of the grid the head moves over, with where its tail has been.
+struct Rope<'a> {
+    knots: &'a [(isize, isize)],
+    tail_visits: &'a HashSet<(isize, isize)>,
+    top_left: (isize, isize),
+    bottom_right: (isize, isize),
+}
+
+impl Rope<'_> {
+    /// The rows and columns the head moves over, which the rest of the rope never leaves.
+    fn bounds(movements: &[(Direction, usize)]) -> ((isize, isize), (isize, isize)) {
+        let mut head = (0, 0);
+        let (mut top_left, mut bottom_right) = (head, head);
+
+        for &(ref direction, times) in movements {
+            for _ in 0..times {
+                move_head(direction, &mut head);
+
+                top_left = (top_left.0.min(head.0), top_left.1.min(head.1));
+                bottom_right = (bottom_right.0.max(head.0), bottom_right.1.max(head.1));
+            }
+        }
+
+        (top_left, bottom_right)
+    }
+
+    fn cell(&self, (row, column): (isize, isize)) -> Option<(usize, usize)> {
+        Some((
+            row.checked_sub(self.top_left.0)?.try_into().ok()?,
+            column.checked_sub(self.top_left.1)?.try_into().ok()?,
+        ))
+    }
+}
+
+impl Visualize for Rope<'_> {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(
+            self.bottom_right.1.abs_diff(self.top_left.1) + 1,
+            self.bottom_right.0.abs_diff(self.top_left.0) + 1,
+        );
+
+        for &visit in self.tail_visits {
+            if let Some((row, column)) = self.cell(visit) {
+                frame.set(row, column, Cell::new('#', Color::GRAY));
+            }
+        }
+
+        // the head goes last, so that it is drawn over the knots it is on
+        for (index, &knot) in self.knots.iter().enumerate().rev() {
+            let cell = match index {
+                0 => Cell::new('H', Color::RED),
+                _ if index + 1 == self.knots.len() => Cell::new('T', Color::GREEN),
+                _ => Cell::new('o', Color::WHITE),
+            };
+
+            if let Some((row, column)) = self.cell(knot) {
+                frame.set(row, column, cell);
+            }
+        }
+
+        frame
+    }
+}
+
+fn move_rope<const LEN: usize>(movements: &[(Direction, usize)], context: &SolveContext) -> usize {
+    let mut tail_visits = HashSet::<(isize, isize)>::new();
+
+    let mut rope = vec![(0, 0); LEN];
+
+    let (top_left, bottom_right) = if context.is_visualizing() {
+        Rope::bounds(movements)
+    } else {
+        ((0, 0), (0, 0))
+    };
+
+    for &(ref direction, times) in movements {
+        for _ in 0..times {
+            move_head(direction, &mut rope[0]);
+
+            for i in 1..LEN {
+                let (left, right) = rope.split_at_mut(i);
+
+                follow_tail(&left[i - 1], &mut right[0]);
+            }
+
+            tail_visits.insert(rope[LEN - 1]);
+
+            context.visualize(&Rope {
+                knots: &rope,
+                tail_visits: &tail_visits,
+                top_left,
+                bottom_right,
+            });
+        }
+    }
+
+    tail_visits.len()
+}
+
+fn move_head(direction: &Direction, head: &mut (isize, isize)) {
+    match *direction {
+        Direction::Up => head.0 -= 1,
+        Direction::Down => head.0 += 1,
+
+        Direction::Left => head.1 -= 1,
+        Direction::Right => head.1 += 1,
+    }
+}
+
+fn follow_tail(head: &(isize, isize), tail: &mut (isize, isize)) {
+    if head.0.abs_diff(tail.0) >= 2 || head.1.abs_diff(tail.1) >= 2 {
+        // we need to take a single action
+
+        match (head.0.cmp(&tail.0), head.1.cmp(&tail.1)) {
+            (Ordering::Equal, Ordering::Less) => {
+                tail.1 -= 1;
+            },
+            (Ordering::Equal, Ordering::Greater) => {
+                tail.1 += 1;
+            },
+            (Ordering::Less, Ordering::Equal) => {
+                tail.0 -= 1;
+            },
+            (Ordering::Greater, Ordering::Equal) => {
+                tail.0 += 1;
+            },
+            (Ordering::Less, Ordering::Less) => {
+                tail.0 -= 1;
+                tail.1 -= 1;
+            },
+            (Ordering::Less, Ordering::Greater) => {
+                tail.0 -= 1;
+                tail.1 += 1;
+            },
+            (Ordering::Greater, Ordering::Less) => {
+                tail.0 += 1;
+                tail.1 -= 1;
+            },
+            (Ordering::Greater, Ordering::Greater) => {
+                tail.0 += 1;
+                tail.1 += 1;
+            },
+            _ => panic!(),
+        }
+    }
+}
+
+impl Parts for Solution {
+    type Input = Vec<(Direction, usize)>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &SolveContext) -> PartSolution {
+        let r = move_rope::<2>(input, context);
+
+        r.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &SolveContext) -> PartSolution {
+        let r = move_rope::<10>(input, context);
+
+        r.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::Solution;
+        use crate::shared::context::SolveContext;
+        use crate::shared::{PartSolution, Parts as _};
+
+        #[test]
+        fn example_2() {
+            let lines = ["R 5", "U 8", "L 8", "D 3", "R 17", "D 10", "L 25", "U 20"];
+
+            let input = (Solution {}).parse(&lines.join("\n"));
+
+            assert_eq!(
+                PartSolution::USize(36),
+                (Solution {}).part_2(&input, &SolveContext::default())
+            );
+        }
+    }
+}
@@ -0,0 +1,253 @@
+#![expect(clippy::string_slice, reason = "Advent of Code is ASCII only")]
+
+use crate::shared::context::SolveContext;
+use crate::shared::diagnostics::Level;
+use crate::shared::error::SolveError;
+use crate::shared::ocr::recognize;
+use crate::shared::visualize::{Cell, Color, Frame, Visualize};
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!();
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_lines(input: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+
+    for line in input.lines() {
+        if &line[0..=3] == "addx" {
+            instructions.push(Instruction::Addx(line[5..].parse::<i32>().unwrap()));
+        } else {
+            instructions.push(Instruction::Noop);
+        }
+    }
+    instructions
+}
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+/// The screen as the CRT draws it, with the sprite on the row being drawn.
+struct Crt<'a> {
+    screen: &'a [Vec<char>],
+    sprite: i32,
+}
+
+impl Visualize for Crt<'_> {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(CRT_WIDTH, CRT_HEIGHT);
+
+        for (row, pixels) in self.screen.iter().enumerate() {
+            for (column, &pixel) in pixels.iter().enumerate() {
+                let cell = if pixel == '#' {
+                    Cell::new('#', Color::GREEN)
+                } else {
+                    Cell::new('.', Color::GRAY)
+                };
+
+                frame.set(row, column, cell);
+            }
+        }
+
+        let row = self.screen.len().saturating_sub(1);
+        let drawn = self.screen.last().map_or(0, Vec::len);
+
+        for column in (self.sprite - 1)..=(self.sprite + 1) {
+            if let Ok(column) = usize::try_from(column)
+                && column >= drawn
+            {
+                frame.set(row, column, Cell::new('=', Color::BLUE));
+            }
+        }
+
+        frame
+    }
+}
+
+fn draw_crt(instructions: &[Instruction], context: &SolveContext) -> Vec<Vec<char>> {
+    // register
+    let mut x: i32 = 1;
+
+    let mut cycle: usize = 1;
+
+    let mut instructions_iter = instructions.iter().copied();
+
+    let mut slot = Option::<Instruction>::None;
+
+    let mut result = vec![vec![]];
+
+    let mut slot_is_fresh;
+
+    loop {
+        slot_is_fresh = false;
+
+        if let Some(Instruction::Noop) | None = slot {
+            if let Some(next) = instructions_iter.next() {
+                slot = Some(next);
+                slot_is_fresh = true;
+            } else {
+                break;
+            }
+        }
+
+        if cycle > CRT_WIDTH {
+            cycle = 1;
+            result.push(vec![]);
+        }
+
+        {
+            let cycle = i32::try_from(cycle).unwrap();
+
+            let last = result.last_mut().unwrap();
+
+            if (cycle - 1) >= x - 1 && (cycle - 1) <= x + 1 {
+                last.push('#');
+            } else {
+                last.push('.');
+            }
+        }
+
+        context.visualize(&Crt {
+            screen: &result,
+            sprite: x,
+        });
+
+        if !slot_is_fresh && let Some(Instruction::Addx(addx)) = slot {
+            x += addx;
+            slot = None;
+        }
+
+        cycle += 1;
+    }
+
+    result
+}
+
+fn sum_signal_strength(instructions: &[Instruction]) -> i32 {
+    // register
+    let mut x: i32 = 1;
+    let mut cycle: usize = 1;
+
+    let mut instructions_iter = instructions.iter().copied();
+
+    let mut slot = Option::<Instruction>::None;
+
+    // sum of signal strengths
+    let mut sum = 0;
+
+    let mut slot_is_fresh;
+
+    loop {
+        slot_is_fresh = false;
+
+        if let Some(Instruction::Noop) | None = slot {
+            if let Some(next) = instructions_iter.next() {
+                slot = Some(next);
+                slot_is_fresh = true;
+            } else {
+                break;
+            }
+        }
+
+        if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+            sum += i32::try_from(cycle).unwrap() * x;
+        }
+
+        if !slot_is_fresh && let Some(Instruction::Addx(addx)) = slot {
+            x += addx;
+            slot = None;
+        }
+
+        cycle += 1;
+    }
+
+    sum
+}
+
+impl TryParts for Solution {
+    type Input = Vec<Instruction>;
+
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(parse_lines(input))
+    }
+
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let sum = sum_signal_strength(input);
+
+        Ok(sum.into())
+    }
+
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let screen = draw_crt(input, context)
+            .into_iter()
+            .map(|x| x.into_iter().collect::<String>())
+            .collect::<Vec<String>>();
+
+        context.report(Level::Debug, || screen.join("\n"));
+
+        let letters = recognize(&screen).map_err(|error| {
+            SolveError::assumption(format!("the CRT does not spell letters: {}", error))
+        })?;
+
+        Ok(PartSolution::String(letters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_2 {
+        use pretty_assertions::assert_eq;
+
+        use super::super::{PUZZLE, Solution, draw_crt};
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+        use crate::shared::solution::read_examples;
+
+        #[test]
+        fn draws_stripes() {
+            // the example draws stripes rather than letters, so it has no answer, and its drawing is checked instead
+            for (example, input) in read_examples(*PUZZLE) {
+                let input = (Solution {}).try_parse(&input).unwrap();
+
+                let screen = draw_crt(&input, &example.context())
+                    .into_iter()
+                    .map(|row| row.into_iter().collect::<String>())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    vec![
+                        "##..##..##..##..##..##..##..##..##..##..",
+                        "###...###...###...###...###...###...###.",
+                        "####....####....####....####....####....",
+                        "#####.....#####.....#####.....#####.....",
+                        "######......######......######......####",
+                        "#######.......#######.......#######.....",
+                    ],
+                    screen,
+                    "{}",
+                    example.set
+                );
+
+                assert!(
+                    matches!(
+                        (Solution {}).try_part_2(&input, &example.context()),
+                        Err(SolveError::Assumption(_))
+                    ),
+                    "stripes are not letters"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+use crate::shared::context::SolveContext;
+use crate::shared::error::SolveError;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!();
+
+#[derive(Clone)]
+enum Operation {
+    Plus(Operand),
+    Times(Operand),
+}
+
+#[derive(Clone)]
+enum Operand {
+    Old,
+    Scalar(i64),
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: usize,
+    activity: usize,
+    items: VecDeque<i64>,
+    operation: Operation,
+    test: i64,
+    target_true: usize,
+    target_false: usize,
+}
+
+/// The description of a single monkey, spanning several lines of the input.
+struct Block {
+    text: String,
+    /// The 0-based index of the block's first line in the input.
+    first_line_index: usize,
+}
+
+impl Block {
+    /// Parses the number `value`, found at byte `offset` into the block.
+    fn number<T: FromStr>(&self, value: &str, offset: usize) -> Result<T, SolveError> {
+        value.parse().map_err(|_| {
+            // everything the regex matched before the number is ASCII, so bytes are characters
+            let mut lines = self.text.as_bytes()[..offset].split(|&byte| byte == b'\n');
+            let index = lines.next_back().map_or(0, <[u8]>::len);
+
+            SolveError::parse_at(
+                self.first_line_index + lines.count(),
+                index,
+                format!("`{}` is out of range", value),
+            )
+        })
+    }
+
+    /// Parses the number captured as `name`.
+    fn capture<T: FromStr>(&self, cap: &Captures<'_>, name: &str) -> Result<T, SolveError> {
+        let capture = cap.name(name).unwrap();
+
+        self.number(capture.as_str(), capture.start())
+    }
+}
+
+fn parse_monkey(regex: &Regex, block: &Block, expected_id: usize) -> Result<Monkey, SolveError> {
+    let Some(cap) = regex.captures(&block.text) else {
+        return Err(SolveError::parse_at(
+            block.first_line_index,
+            0,
+            format!("expected the description of monkey {}", expected_id),
+        ));
+    };
+
+    let monkey_id: usize = block.capture(&cap, "monkey")?;
+
+    if monkey_id != expected_id {
+        return Err(SolveError::assumption(format!(
+            "expected monkey {}, found monkey {}",
+            expected_id, monkey_id
+        )));
+    }
+
+    let mut items = VecDeque::new();
+
+    if let Some(capture) = cap.name("items") {
+        let mut offset = capture.start();
+
+        for item in capture.as_str().split(", ") {
+            items.push_back(block.number(item, offset)?);
+
+            offset += item.len() + ", ".len();
+        }
+    }
+
+    let operand = if &cap["operand"] == "old" {
+        Operand::Old
+    } else {
+        Operand::Scalar(block.capture(&cap, "operand")?)
+    };
+
+    let operation = if &cap["operation"] == "+" {
+        Operation::Plus(operand)
+    } else {
+        Operation::Times(operand)
+    };
+
+    let divisible: i64 = block.capture(&cap, "divisible")?;
+
+    if divisible == 0 {
+        return Err(SolveError::assumption(format!(
+            "monkey {} tests for divisibility by 0",
+            monkey_id
+        )));
+    }
+
+    Ok(Monkey {
+        id: monkey_id,
+        activity: 0,
+        items,
+        operation,
+        test: divisible,
+        target_true: block.capture(&cap, "target_true")?,
+        target_false: block.capture(&cap, "target_false")?,
+    })
+}
+
+fn parse_lines(input: &str) -> Result<Vec<Monkey>, SolveError> {
+    let regex = Regex::new(
+        r"^Monkey (?P<monkey>\d+):\n  Starting items: (?P<items>\d+(?:, \d+)*)?\n  Operation: new = old (?P<operation>\*|\+) (?P<operand>\d+|old)\n  Test: divisible by (?P<divisible>\d+)\n    If true: throw to monkey (?P<target_true>\d+)\n    If false: throw to monkey (?P<target_false>\d+)$",
+    ).unwrap();
+
+    let lines = input
+        .lines()
+        .map(str::trim_end)
+        .enumerate()
+        .collect::<Vec<_>>();
+
+    // every monkey is described by a block of lines, separated by empty ones
+    let monkeys = lines
+        .split(|&(_, line)| line.is_empty())
+        .filter(|lines| !lines.is_empty())
+        .map(|lines| Block {
+            text: lines
+                .iter()
+                .map(|&(_, line)| line)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            first_line_index: lines[0].0,
+        })
+        .enumerate()
+        .map(|(expected_id, block)| parse_monkey(&regex, &block, expected_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if monkeys.len() < 2 {
+        return Err(SolveError::assumption(
+            "monkey business needs at least two monkeys",
+        ));
+    }
+
+    if let Some(monkey) = monkeys
+        .iter()
+        .find(|monkey| monkey.target_true.max(monkey.target_false) >= monkeys.len())
+    {
+        return Err(SolveError::assumption(format!(
+            "monkey {} throws to a monkey that does not exist",
+            monkey.id
+        )));
+    }
+
+    Ok(monkeys)
+}
+
+fn execute_operation(input: i64, operation: &Operation) -> i64 {
+    match *operation {
+        Operation::Plus(Operand::Old) => input + input,
+        Operation::Times(Operand::Old) => input * input,
+        Operation::Plus(Operand::Scalar(s)) => input + s,
+        Operation::Times(Operand::Scalar(s)) => input * s,
+    }
+}
+
+enum WorryReducer {
+    Divide,
+    Mod(i64),
+}
+
+fn monkey_business(monkeys: &mut [Monkey], worry_reducer: &WorryReducer) {
+    for i in 0..monkeys.len() {
+        let mut items = std::mem::take(&mut monkeys[i].items);
+
+        while let Some(item) = items.pop_front() {
+            // this monkey inspected something
+            (monkeys[i].activity) += 1;
+
+            let result = execute_operation(item, &monkeys[i].operation);
+
+            let result = match *worry_reducer {
+                WorryReducer::Divide => result / 3,
+                WorryReducer::Mod(m) => result.rem_euclid(m),
+            };
+
+            let target_monkey = if 0 == (result % monkeys[i].test) {
+                monkeys[i].target_true
+            } else {
+                monkeys[i].target_false
+            };
+
+            monkeys[target_monkey].items.push_back(result);
+        }
+    }
+}
+
+fn do_monkey_business(monkeys: &[Monkey], times: usize, worry_reducer: &WorryReducer) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    (1..=times).for_each(|_| {
+        monkey_business(&mut monkeys, worry_reducer);
+    });
+
+    monkeys.sort_unstable_by_key(|b| std::cmp::Reverse(b.activity));
+
+    monkeys[0].activity * monkeys[1].activity
+}
+
+fn do_monkey_business_part_1(monkeys: &[Monkey]) -> usize {
+    do_monkey_business(monkeys, 20, &WorryReducer::Divide)
+}
+
+fn do_monkey_business_part_2(monkeys: &[Monkey]) -> usize {
+    let m = monkeys.iter().fold(1, |acc, curr| acc * curr.test);
+
+    do_monkey_business(monkeys, 10_000, &WorryReducer::Mod(m))
+}
+
+impl TryParts for Solution {
+    type Input = Vec<Monkey>;
+
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
+
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let result = do_monkey_business_part_1(input);
+
+        Ok(result.into())
+    }
+
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let result = do_monkey_business_part_2(input);
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use super::super::{PUZZLE, Solution};
+        use crate::shared::TryParts as _;
+        use crate::shared::error::SolveError;
+        use crate::shared::solution::read_file;
+
+        #[test]
+        fn malformed() {
+            let example = read_file("examples", *PUZZLE);
+
+            assert!(
+                matches!(
+                    (Solution {}).try_parse(&example.replacen(
+                        "divisible by 23",
+                        "divisible by x",
+                        1
+                    )),
+                    Err(SolveError::Parse { line: 1, .. })
+                ),
+                "the first monkey's test is not a number"
+            );
+
+            assert!(
+                matches!(
+                    (Solution {}).try_parse(&example.replacen(
+                        "throw to monkey 3",
+                        "throw to monkey 7",
+                        1
+                    )),
+                    Err(SolveError::Assumption(_))
+                ),
+                "there is no monkey 7"
+            );
+        }
+    }
+}
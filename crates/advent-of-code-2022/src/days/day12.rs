@@ -0,0 +1,322 @@
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+
+use crate::shared::context::SolveContext;
+use crate::shared::error::SolveError;
+use crate::shared::{PartSolution, TryParts};
+
+crate::solution!();
+
+type Coordinates = (usize, usize);
+
+#[derive(Clone)]
+enum Direction {
+    Ascending,
+    Descending,
+}
+
+#[derive(PartialEq)]
+pub enum Cell {
+    Start,
+    End,
+    Value(u8),
+}
+
+impl Cell {
+    fn elevation(&self) -> u8 {
+        match *self {
+            Cell::Start => 0,
+            Cell::End => 25,
+            Cell::Value(v) => v,
+        }
+    }
+}
+
+fn parse_lines(input: &str) -> Result<Vec<Vec<Cell>>, SolveError> {
+    let mut result = Vec::new();
+    for (line_index, line) in input.lines().enumerate() {
+        let mut parsed_line = Vec::<_>::new();
+
+        for (index, character) in line.chars().enumerate() {
+            let v = match character {
+                'S' => Cell::Start,
+                'E' => Cell::End,
+                'a'..='z' => {
+                    // this way 'a' becomes '0'
+                    Cell::Value(u8::try_from(character).unwrap() - b'a')
+                },
+                _ => {
+                    return Err(SolveError::parse_at(
+                        line_index,
+                        index,
+                        format!(
+                            "expected `S`, `E` or an elevation from `a` to `z`, found `{}`",
+                            character
+                        ),
+                    ));
+                },
+            };
+
+            parsed_line.push(v);
+        }
+
+        result.push(parsed_line);
+    }
+
+    Ok(result)
+}
+
+fn find_start(field: &[Vec<Cell>]) -> Result<(usize, usize), SolveError> {
+    for (row_index, row) in field.iter().enumerate() {
+        for (column_index, value) in row.iter().enumerate() {
+            if Cell::Start == *value {
+                return Ok((row_index, column_index));
+            }
+        }
+    }
+
+    Err(SolveError::assumption("no start `S` in the height map"))
+}
+
+fn find_goal(field: &[Vec<Cell>]) -> Result<(usize, usize), SolveError> {
+    for (row_index, row) in field.iter().enumerate() {
+        for (column_index, value) in row.iter().enumerate() {
+            if Cell::End == *value {
+                return Ok((row_index, column_index));
+            }
+        }
+    }
+
+    Err(SolveError::assumption("no goal `E` in the height map"))
+}
+
+fn find_shortest_distance(field: &[Vec<Cell>]) -> Result<usize, SolveError> {
+    let start = find_start(field)?;
+
+    // the goal has to exist, even if we don't start there
+    find_goal(field)?;
+
+    let r = a_star(field, start, &Direction::Ascending)?;
+
+    // don't add the start position
+    Ok(r.len() - 1)
+}
+
+fn get_neighbors<'f>(
+    field: &'f [Vec<Cell>],
+    coordinates: &'f Coordinates,
+    direction: &'f Direction,
+) -> impl Iterator<Item = Coordinates> + 'f {
+    let &(ref row_index, ref column_index) = coordinates;
+
+    let transformations = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+    transformations
+        .into_iter()
+        .filter_map(|(r, c)| {
+            Some((
+                row_index.checked_add_signed(r)?,
+                column_index.checked_add_signed(c)?,
+            ))
+        })
+        .filter(|&(r, c)| field.get(r).and_then(|row| row.get(c)).is_some())
+        .filter(|&(r, c)| match *direction {
+            Direction::Ascending => {
+                field[r][c].elevation() <= field[*row_index][*column_index].elevation() + 1
+            },
+            Direction::Descending => {
+                field[r][c].elevation() + 1 >= field[*row_index][*column_index].elevation()
+            },
+        })
+}
+
+fn reconstruct_path(
+    came_from: &HashMap<Coordinates, Coordinates>,
+    mut current: Coordinates,
+) -> Vec<Coordinates> {
+    let mut total_path = vec![current];
+
+    while let Some(c) = came_from.get(&current) {
+        total_path.push(*c);
+
+        current = *c;
+    }
+
+    total_path.reverse();
+    total_path
+}
+
+fn distance(
+    field: &[Vec<Cell>],
+    current: Coordinates,
+    neighbor: Coordinates,
+    direction: &Direction,
+) -> u32 {
+    match *direction {
+        Direction::Ascending => {
+            match (field[current.0][current.1].elevation())
+                .cmp(&(field[neighbor.0][neighbor.1].elevation()))
+            {
+                std::cmp::Ordering::Less => 0,
+                std::cmp::Ordering::Equal => 10,
+                std::cmp::Ordering::Greater => 100,
+            }
+        },
+        Direction::Descending => distance(field, neighbor, current, &Direction::Ascending),
+    }
+}
+
+fn heuristic(field: &[Vec<Cell>], current: Coordinates) -> u32 {
+    u32::from(field[current.0][current.1].elevation())
+}
+
+#[derive(PartialEq, Eq)]
+struct Node(Coordinates, u32);
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.1.cmp(&self.1)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn a_star(
+    field: &[Vec<Cell>],
+    start: Coordinates,
+    direction: &Direction,
+) -> Result<Vec<Coordinates>, SolveError> {
+    let mut open_set = BinaryHeap::from([Node(start, heuristic(field, start))]);
+
+    let mut came_from = HashMap::<Coordinates, Coordinates>::new();
+
+    let mut g_score: HashMap<_, _> = HashMap::from([(start, 0)]);
+
+    while let Some(current) = open_set.pop() {
+        let current = current.0;
+
+        match *direction {
+            Direction::Ascending => {
+                if field[current.0][current.1] == Cell::End {
+                    return Ok(reconstruct_path(&came_from, current));
+                }
+            },
+            Direction::Descending => {
+                if field[current.0][current.1] == Cell::Start
+                    || field[current.0][current.1] == Cell::Value(0)
+                {
+                    return Ok(reconstruct_path(&came_from, current));
+                }
+            },
+        }
+
+        let neighbors = get_neighbors(field, &current, direction);
+
+        for neighbor in neighbors {
+            let tentative_g_score =
+                g_score.get(&current).unwrap() + distance(field, current, neighbor, direction);
+
+            if tentative_g_score < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
+                came_from.insert(neighbor, current);
+
+                g_score.insert(neighbor, tentative_g_score);
+
+                let g_score_with_heuristic = tentative_g_score + heuristic(field, neighbor);
+
+                open_set.push(Node(neighbor, g_score_with_heuristic));
+            }
+        }
+    }
+
+    Err(SolveError::no_solution(match *direction {
+        Direction::Ascending => "the goal `E` cannot be reached from the start `S`",
+        Direction::Descending => "no elevation `a` can reach the goal `E`",
+    }))
+}
+
+fn find_first_a_from_end(field: &[Vec<Cell>]) -> Result<usize, SolveError> {
+    let start = find_goal(field)?;
+
+    let r = a_star(field, start, &Direction::Descending)?;
+
+    // don't add the start position
+    Ok(r.len() - 1)
+}
+
+impl TryParts for Solution {
+    type Input = Vec<Vec<Cell>>;
+
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input)
+    }
+
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let result = find_shortest_distance(input)?;
+
+        Ok(result.into())
+    }
+
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        _context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        let result = find_first_a_from_end(input)?;
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part_1 {
+        use super::super::Solution;
+        use crate::shared::TryParts as _;
+        use crate::shared::context::SolveContext;
+        use crate::shared::error::SolveError;
+
+        #[test]
+        fn malformed() {
+            assert!(
+                matches!(
+                    (Solution {}).try_parse("Sab\naB?E"),
+                    Err(SolveError::Parse {
+                        line: 2,
+                        column: 2,
+                        ..
+                    })
+                ),
+                "`B` is not an elevation"
+            );
+
+            let no_start = (Solution {}).try_parse("aab\nabE").unwrap();
+
+            assert!(
+                matches!(
+                    (Solution {}).try_part_1(&no_start, &SolveContext::default()),
+                    Err(SolveError::Assumption(_))
+                ),
+                "there is no start"
+            );
+
+            let unreachable = (Solution {}).try_parse("Saz\nazE").unwrap();
+
+            assert!(
+                matches!(
+                    (Solution {}).try_part_1(&unreachable, &SolveContext::default()),
+                    Err(SolveError::NoSolution(_))
+                ),
+                "the goal is too high to climb to"
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::fmt::Write as _;
+use std::slice;
+
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Pair {
+    Vec(Vec<Pair>),
+    Value(u32),
+}
+
+impl std::fmt::Debug for Pair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Pair::Vec(ref v) => {
+                f.write_str("[")?;
+
+                let mut s = String::new();
+
+                for (i, p) in v.iter().enumerate() {
+                    write!(s, "{:?}", p)?;
+
+                    if i + 1 != v.len() {
+                        s.push(',');
+                    }
+                }
+
+                f.write_str(s.as_str())?;
+
+                f.write_str("]")?;
+            },
+            Pair::Value(v) => {
+                f.write_str(&v.to_string())?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+fn find_right_orders(packets: &[Vec<Pair>]) -> usize {
+    let mut sum = 0;
+    for (index, pair) in packets.chunks(2).enumerate() {
+        let &[ref pair_1, ref pair_2] = pair else {
+            panic!("Packets come in pairs");
+        };
+
+        if Ordering::Less == determine_largest(pair_1, pair_2) {
+            sum += index + 1;
+        }
+    }
+
+    sum
+}
+
+fn parse_line(chars: &[char]) -> (Pair, usize) {
+    let mut buffer = String::new();
+
+    let mut v = Vec::new();
+
+    let mut current = 0;
+
+    while current < chars.len() {
+        match chars[current] {
+            '[' => {
+                let (pair, skipped) = parse_line(&chars[current + 1..]);
+
+                v.push(pair);
+
+                current += skipped + 1;
+            },
+            ']' => {
+                if !buffer.is_empty() {
+                    let value = Pair::Value(str::parse(&buffer).unwrap());
+
+                    buffer.clear();
+
+                    v.push(value);
+                }
+
+                return (Pair::Vec(v), current + 1);
+            },
+            ',' => {
+                if !buffer.is_empty() {
+                    let value = Pair::Value(str::parse(&buffer).unwrap());
+
+                    buffer.clear();
+
+                    v.push(value);
+                }
+
+                current += 1;
+            },
+            d @ '0'..='9' => {
+                buffer.push(d);
+
+                current += 1;
+            },
+            _ => {
+                panic!()
+            },
+        }
+    }
+
+    (Pair::Vec(v), current + 1)
+}
+
+fn as_vec(pair: Pair) -> Vec<Pair> {
+    if let Pair::Vec(v) = pair { v } else { panic!() }
+}
+
+fn parse_lines(input: &str) -> Vec<Vec<Pair>> {
+    let mut result = Vec::new();
+
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let (pair, _) = parse_line(&(line.chars().collect::<Vec<char>>()));
+
+        result.push(as_vec(pair));
+    }
+
+    result
+}
+
+fn determine_largest(left: &[Pair], right: &[Pair]) -> Ordering {
+    match (left, right) {
+        (&[Pair::Value(v1)], &[Pair::Value(ref v2)]) => v1.cmp(v2),
+        (l, &[ref r @ Pair::Value(_)]) => determine_largest(l, &[Pair::Vec(vec![r.clone()])]),
+        (&[ref l @ Pair::Value(_)], r) => determine_largest(&[Pair::Vec(vec![l.clone()])], r),
+        (&[Pair::Vec(ref l)], &[Pair::Vec(ref r)]) => determine_largest(l, r),
+        (&[], &[_, ..]) => std::cmp::Ordering::Less,
+        (&[_, ..], &[]) => std::cmp::Ordering::Greater,
+        (&[ref l, ref l_rest @ ..], &[ref r, ref r_rest @ ..]) => {
+            match determine_largest(slice::from_ref(l), slice::from_ref(r)) {
+                Ordering::Less => Ordering::Less,
+                Ordering::Greater => Ordering::Greater,
+                Ordering::Equal => determine_largest(l_rest, r_rest),
+            }
+        },
+        (&[], &[]) => Ordering::Equal,
+    }
+}
+
+fn sort_and_find_position(list: &[Vec<Pair>]) -> usize {
+    let mut list = list.to_vec();
+
+    let p_2 = vec![Pair::Vec(vec![Pair::Vec(vec![Pair::Value(2)])])];
+    let p_6 = vec![Pair::Vec(vec![Pair::Vec(vec![Pair::Value(6)])])];
+
+    list.push(p_2.clone());
+    list.push(p_6.clone());
+
+    list.sort_by(|x, y| determine_largest(x, y));
+
+    let position_1 = list.iter().position(|x| x == &p_2);
+    let position_2 = list.iter().position(|x| x == &p_6);
+
+    (position_1.unwrap() + 1) * (position_2.unwrap() + 1)
+}
+
+impl Parts for Solution {
+    type Input = Vec<Vec<Pair>>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_lines(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let sum = find_right_orders(input);
+
+        sum.into()
+    }
+
+    fn part_2(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        let sum = sort_and_find_position(input);
+
+        sum.into()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::shared::context::{DeclaredParam, Param, SolveContext};
+use crate::shared::{PartSolution, Parts};
+
+crate::solution!();
+
+/// The decryption key every number is multiplied by before mixing.
+const KEY: Param<i64> = Param::new("key", 811_589_153);
+
+fn parse_input(input: &str) -> Vec<i64> {
+    input
+        .trim()
+        .lines()
+        .map(|l| l.parse::<i64>().unwrap())
+        .collect()
+}
+
+fn decode(input: &[i64], key: i64, times: usize) -> PartSolution {
+    let mut numbers: Vec<(usize, i64)> = input.iter().map(|v| v * key).enumerate().collect();
+
+    for _ in 0..times {
+        for original_index in 0..numbers.len() {
+            let old_index = numbers.iter().position(|x| x.0 == original_index).unwrap();
+
+            let value = numbers[old_index].1;
+
+            // calculate new position
+            let new_index = isize::try_from(old_index).unwrap() + isize::try_from(value).unwrap();
+
+            // % doesn't work for negative values.
+            // move by length - 1 otherwise we move one too far
+            let new_index =
+                usize::try_from(new_index.rem_euclid(isize::try_from(numbers.len()).unwrap() - 1))
+                    .unwrap();
+
+            // move position
+            let tmp = numbers.remove(old_index);
+            numbers.insert(new_index, tmp);
+        }
+    }
+
+    let zero = numbers.iter().position(|&(_, value)| value == 0).unwrap();
+
+    let thousand = numbers[(zero + 1_000) % numbers.len()].1;
+    let two_thousand = numbers[(zero + 2_000) % numbers.len()].1;
+    let three_thousand = numbers[(zero + 3_000) % numbers.len()].1;
+
+    PartSolution::I64(thousand + two_thousand + three_thousand)
+}
+
+impl Parts for Solution {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        decode(input, 1, 1)
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &SolveContext) -> PartSolution {
+        decode(input, context.get(&KEY), 10)
+    }
+
+    fn params(&self) -> Vec<DeclaredParam> {
+        vec![KEY.declare()]
+    }
+}
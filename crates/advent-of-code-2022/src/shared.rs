@@ -1,13 +1,111 @@
 use std::cmp::Ordering;
 
+use context::{DeclaredParam, SolveContext};
+use error::SolveError;
+use serde::{Deserialize, Serialize, Serializer};
+
+pub mod answers;
+pub mod baseline;
+pub mod context;
 pub mod day;
+pub mod diagnostics;
+pub mod error;
+pub mod examples;
+pub mod fetch;
 pub mod grids;
+pub mod input;
+pub mod memory;
+pub mod ocr;
+pub mod pool;
+pub mod report;
+pub mod runner;
 pub mod solution;
+pub mod submit;
+pub mod summary;
+#[cfg(test)]
+pub mod testing;
 pub mod tree;
+pub mod visualize;
+pub mod watch;
 
 pub trait Parts {
-    fn part_1(&self, input: &str) -> PartSolution;
-    fn part_2(&self, input: &str) -> PartSolution;
+    /// The puzzle input, parsed once and shared by both parts.
+    type Input;
+
+    fn parse(&self, input: &str) -> Self::Input;
+    /// Solves part 1, with the puzzle parameters of `context`.
+    fn part_1(&self, input: &Self::Input, context: &SolveContext) -> PartSolution;
+    /// Solves part 2, with the puzzle parameters of `context`.
+    fn part_2(&self, input: &Self::Input, context: &SolveContext) -> PartSolution;
+
+    /// The puzzle parameters the parts read from their context, against which overrides are checked.
+    fn params(&self) -> Vec<DeclaredParam> {
+        Vec::new()
+    }
+}
+
+/// The fallible counterpart of [`Parts`], for days that validate their input.
+///
+/// Every [`Parts`] implementation is a [`TryParts`] that never fails.
+pub trait TryParts {
+    /// The puzzle input, parsed once and shared by both parts.
+    type Input;
+
+    /// # Errors
+    ///
+    /// When the input is malformed.
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError>;
+
+    /// # Errors
+    ///
+    /// When the input violates an assumption of the puzzle, or no answer can be found.
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError>;
+
+    /// # Errors
+    ///
+    /// When the input violates an assumption of the puzzle, or no answer can be found.
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError>;
+
+    /// The puzzle parameters the parts read from their context, against which overrides are checked.
+    fn params(&self) -> Vec<DeclaredParam> {
+        Vec::new()
+    }
+}
+
+impl<T: Parts> TryParts for T {
+    type Input = T::Input;
+
+    fn try_parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(self.parse(input))
+    }
+
+    fn try_part_1(
+        &self,
+        input: &Self::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(self.part_1(input, context))
+    }
+
+    fn try_part_2(
+        &self,
+        input: &Self::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        Ok(self.part_2(input, context))
+    }
+
+    fn params(&self) -> Vec<DeclaredParam> {
+        Parts::params(self)
+    }
 }
 
 pub enum PartSolution {
@@ -15,6 +113,8 @@ pub enum PartSolution {
     U32(u32),
     I64(i64),
     U64(u64),
+    I128(i128),
+    U128(u128),
     ISize(isize),
     USize(usize),
     String(String),
@@ -30,6 +130,8 @@ impl std::fmt::Debug for PartSolution {
             Self::U32(arg0) => write!(f, "{}u32", arg0),
             Self::I64(arg0) => write!(f, "{}i64", arg0),
             Self::U64(arg0) => write!(f, "{}u64", arg0),
+            Self::I128(arg0) => write!(f, "{}i128", arg0),
+            Self::U128(arg0) => write!(f, "{}u128", arg0),
             Self::ISize(arg0) => write!(f, "{}isize", arg0),
             Self::USize(arg0) => write!(f, "{}usize", arg0),
             Self::String(ref arg0) => write!(f, "\"{}\"", arg0),
@@ -40,66 +142,216 @@ impl std::fmt::Debug for PartSolution {
     }
 }
 
-impl PartSolution {
+/// Numbers serialize as numbers, text as a string, lines as a sequence of strings, and the rest as nothing.
+impl Serialize for PartSolution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            PartSolution::I32(integer) => serializer.serialize_i32(integer),
+            PartSolution::U32(integer) => serializer.serialize_u32(integer),
+            PartSolution::I64(integer) => serializer.serialize_i64(integer),
+            PartSolution::U64(integer) => serializer.serialize_u64(integer),
+            PartSolution::I128(integer) => serializer.serialize_i128(integer),
+            PartSolution::U128(integer) => serializer.serialize_u128(integer),
+            PartSolution::ISize(integer) => integer.serialize(serializer),
+            PartSolution::USize(integer) => integer.serialize(serializer),
+            PartSolution::String(ref text) => serializer.serialize_str(text),
+            PartSolution::Vec(ref lines) => lines.serialize(serializer),
+            PartSolution::Manual | PartSolution::None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Any integer variant of a [`PartSolution`], widened so that every two of them compare by value.
+///
+/// TOML integers stop at [`i64`], so larger ones are written as strings of digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "TomlInteger", into = "TomlInteger")]
+pub enum Integer {
+    /// Below zero, and so below every [`Integer::NonNegative`].
+    Negative(i128),
+    NonNegative(u128),
+}
+
+impl Integer {
     #[must_use]
-    pub fn has_solution(&self) -> bool {
-        !matches!(*self, PartSolution::None)
+    pub fn signed(integer: i128) -> Self {
+        match u128::try_from(integer) {
+            Ok(integer) => Integer::NonNegative(integer),
+            Err(_) => Integer::Negative(integer),
+        }
     }
 }
 
-impl PartialEq<PartSolution> for PartSolution {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *self {
-            PartSolution::I32(ref i) => i == other,
-            PartSolution::U32(ref i) => i == other,
-            PartSolution::I64(ref i) => i == other,
-            PartSolution::U64(ref i) => i == other,
-            PartSolution::ISize(ref i) => i == other,
-            PartSolution::USize(ref i) => i == other,
-            PartSolution::String(ref i) => i == other,
-            PartSolution::Vec(ref i) => i == other,
-            PartSolution::None => matches!(other, &PartSolution::None),
-            PartSolution::Manual => matches!(other, &PartSolution::Manual),
+impl From<Integer> for PartSolution {
+    fn from(integer: Integer) -> Self {
+        match integer {
+            Integer::Negative(integer) => PartSolution::I128(integer),
+            Integer::NonNegative(integer) => PartSolution::U128(integer),
         }
     }
 }
 
-impl From<i32> for PartSolution {
-    fn from(v: i32) -> Self {
-        PartSolution::I32(v)
+impl std::fmt::Display for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Integer::Negative(integer) => write!(f, "{}", integer),
+            Integer::NonNegative(integer) => write!(f, "{}", integer),
+        }
     }
 }
 
-impl From<u32> for PartSolution {
-    fn from(v: u32) -> Self {
-        PartSolution::U32(v)
+/// An [`Integer`] as TOML holds it.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum TomlInteger {
+    Small(i64),
+    /// Only beyond [`i64`], and exactly as [`Integer`] displays it, so that no text is mistaken for a number.
+    Large(String),
+}
+
+impl From<Integer> for TomlInteger {
+    fn from(integer: Integer) -> Self {
+        let small = match integer {
+            Integer::Negative(integer) => i64::try_from(integer).ok(),
+            Integer::NonNegative(integer) => i64::try_from(integer).ok(),
+        };
+
+        small.map_or_else(
+            || TomlInteger::Large(integer.to_string()),
+            TomlInteger::Small,
+        )
     }
 }
 
-impl From<i64> for PartSolution {
-    fn from(v: i64) -> Self {
-        PartSolution::I64(v)
+impl TryFrom<TomlInteger> for Integer {
+    type Error = &'static str;
+
+    fn try_from(integer: TomlInteger) -> Result<Self, Self::Error> {
+        let large = match integer {
+            TomlInteger::Small(integer) => return Ok(Integer::signed(integer.into())),
+            TomlInteger::Large(large) => large,
+        };
+
+        let integer = match large.parse::<u128>() {
+            Ok(integer) => Integer::NonNegative(integer),
+            Err(_) => Integer::Negative(large.parse().map_err(|_| "expecting an integer")?),
+        };
+
+        match TomlInteger::from(integer) {
+            TomlInteger::Large(written) if written == large => Ok(integer),
+            TomlInteger::Large(_) | TomlInteger::Small(_) => {
+                Err("expecting an integer beyond i64, as digits")
+            },
+        }
     }
 }
 
-impl From<u64> for PartSolution {
-    fn from(v: u64) -> Self {
-        PartSolution::U64(v)
+impl PartSolution {
+    #[must_use]
+    pub fn has_solution(&self) -> bool {
+        !matches!(*self, PartSolution::None)
+    }
+
+    /// The value of an integer variant, whatever its width.
+    fn integer(&self) -> Option<Integer> {
+        match *self {
+            PartSolution::I32(integer) => Some(Integer::signed(integer.into())),
+            PartSolution::U32(integer) => Some(Integer::NonNegative(integer.into())),
+            PartSolution::I64(integer) => Some(Integer::signed(integer.into())),
+            PartSolution::U64(integer) => Some(Integer::NonNegative(integer.into())),
+            PartSolution::I128(integer) => Some(Integer::signed(integer)),
+            PartSolution::U128(integer) => Some(Integer::NonNegative(integer)),
+            PartSolution::ISize(integer) => Some(Integer::signed(
+                i128::try_from(integer).expect("isize is at most 128 bits wide"),
+            )),
+            PartSolution::USize(integer) => Some(Integer::NonNegative(
+                u128::try_from(integer).expect("usize is at most 128 bits wide"),
+            )),
+            PartSolution::String(_)
+            | PartSolution::Vec(_)
+            | PartSolution::Manual
+            | PartSolution::None => None,
+        }
     }
 }
 
-impl From<isize> for PartSolution {
-    fn from(v: isize) -> Self {
-        PartSolution::ISize(v)
+/// Integers are equal by value whatever their variants, everything else only to the same variant.
+impl PartialEq<PartSolution> for PartSolution {
+    fn eq(&self, other: &PartSolution) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
     }
 }
 
-impl From<usize> for PartSolution {
-    fn from(v: usize) -> Self {
-        PartSolution::USize(v)
+/// Integers are ordered by value whatever their variants, text and lines lexicographically, and
+/// [`PartSolution::Manual`] and [`PartSolution::None`] are only equal to themselves. Other variants don't compare.
+impl PartialOrd<PartSolution> for PartSolution {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+        if let (Some(integer), Some(other)) = (self.integer(), other.integer()) {
+            return Some(integer.cmp(&other));
+        }
+
+        match (self, other) {
+            (&PartSolution::String(ref text), &PartSolution::String(ref other)) => {
+                Some(text.cmp(other))
+            },
+            (&PartSolution::Vec(ref lines), &PartSolution::Vec(ref other)) => {
+                Some(lines.cmp(other))
+            },
+            (&PartSolution::Manual, &PartSolution::Manual)
+            | (&PartSolution::None, &PartSolution::None) => Some(Ordering::Equal),
+            _ => None,
+        }
     }
 }
 
+/// Implements the conversion into a [`PartSolution`] and the comparisons with one, both ways, for integers.
+macro_rules! integer_part_solution {
+    ($($integer:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$integer> for PartSolution {
+                fn from(v: $integer) -> Self {
+                    PartSolution::$variant(v)
+                }
+            }
+
+            impl PartialEq<PartSolution> for $integer {
+                fn eq(&self, other: &PartSolution) -> bool {
+                    PartSolution::$variant(*self) == *other
+                }
+            }
+
+            impl PartialEq<$integer> for PartSolution {
+                fn eq(&self, other: &$integer) -> bool {
+                    *self == PartSolution::$variant(*other)
+                }
+            }
+
+            impl PartialOrd<PartSolution> for $integer {
+                fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
+                    PartSolution::$variant(*self).partial_cmp(other)
+                }
+            }
+
+            impl PartialOrd<$integer> for PartSolution {
+                fn partial_cmp(&self, other: &$integer) -> Option<Ordering> {
+                    self.partial_cmp(&PartSolution::$variant(*other))
+                }
+            }
+        )*
+    };
+}
+
+integer_part_solution!(
+    i32 => I32,
+    u32 => U32,
+    i64 => I64,
+    u64 => U64,
+    i128 => I128,
+    u128 => U128,
+    isize => ISize,
+    usize => USize,
+);
+
 impl From<Vec<String>> for PartSolution {
     fn from(v: Vec<String>) -> Self {
         PartSolution::Vec(v)
@@ -134,6 +386,8 @@ impl std::fmt::Display for PartSolution {
             PartSolution::U32(other) => other.to_string(),
             PartSolution::I64(other) => other.to_string(),
             PartSolution::U64(other) => other.to_string(),
+            PartSolution::I128(other) => other.to_string(),
+            PartSolution::U128(other) => other.to_string(),
             PartSolution::ISize(other) => other.to_string(),
             PartSolution::USize(other) => other.to_string(),
             PartSolution::String(ref other) => other.to_owned(),
@@ -146,336 +400,198 @@ impl std::fmt::Display for PartSolution {
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for i32 {
+impl PartialEq<PartSolution> for String {
     fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => *self == other,
-            PartSolution::U32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
+        matches!(*other, PartSolution::String(ref text) if text == self)
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for u32 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => i64::from(*self) == i64::from(other),
-            PartSolution::U32(other) => *self == other,
-            PartSolution::I64(other) => i64::from(*self) == other,
-            PartSolution::U64(other) => u64::from(*self) == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
+impl PartialEq<String> for PartSolution {
+    fn eq(&self, other: &String) -> bool {
+        other == self
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for i64 {
-    fn eq(&self, other: &PartSolution) -> bool {
+impl PartialOrd<PartSolution> for String {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
         match *other {
-            PartSolution::I32(other) => *self == Self::from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => *self == other,
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
+            PartSolution::String(ref text) => Some(self.cmp(text)),
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
             | PartSolution::Vec(_)
             | PartSolution::Manual
-            | PartSolution::None => false,
+            | PartSolution::None => None,
         }
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for u64 {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => *self == Self::from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => *self == other,
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
+impl PartialOrd<String> for PartSolution {
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for isize {
+impl PartialEq<PartSolution> for Vec<String> {
     fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => *self == other,
-            PartSolution::USize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
+        matches!(*other, PartSolution::Vec(ref lines) if lines == self)
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for usize {
-    fn eq(&self, other: &PartSolution) -> bool {
-        match *other {
-            PartSolution::I32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U32(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::I64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::U64(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::ISize(other) => Ok(*self) == Self::try_from(other),
-            PartSolution::USize(other) => *self == other,
-            PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => false,
-        }
+impl PartialEq<Vec<String>> for PartSolution {
+    fn eq(&self, other: &Vec<String>) -> bool {
+        other == self
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for String {
-    fn eq(&self, other: &PartSolution) -> bool {
+impl PartialOrd<PartSolution> for Vec<String> {
+    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
         match *other {
-            PartSolution::String(ref s) => s == self,
+            PartSolution::Vec(ref lines) => Some(self.cmp(lines)),
             PartSolution::I32(_)
             | PartSolution::U32(_)
             | PartSolution::I64(_)
             | PartSolution::U64(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
             | PartSolution::ISize(_)
             | PartSolution::USize(_)
-            | PartSolution::Vec(_)
+            | PartSolution::String(_)
             | PartSolution::Manual
-            | PartSolution::None => false,
+            | PartSolution::None => None,
         }
     }
 }
 
-impl std::cmp::PartialEq<PartSolution> for Vec<String> {
-    fn eq(&self, other: &PartSolution) -> bool {
-        matches!(*other, PartSolution::Vec(ref v) if {
-            if v.len() != self.len() {
-                return false;
-            }
-
-            for (l, r) in self.iter().zip(v) {
-                if l != r {
-                    return false;
-                }
-            }
-
-            true
-        })
+impl PartialOrd<Vec<String>> for PartSolution {
+    fn partial_cmp(&self, other: &Vec<String>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
     }
 }
 
-impl std::cmp::PartialOrd<PartSolution> for i32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(ref other) => self.partial_cmp(other),
-            PartSolution::U32(other) => i64::from(*self).partial_cmp(&i64::from(other)),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller i32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
-        }
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::{Just, Strategy, any, prop_oneof, proptest};
+    use proptest::{collection, prop_assert, prop_assert_eq};
+
+    use crate::shared::PartSolution;
+
+    /// Any [`PartSolution`], with integers clustered around zero and the bounds of each width so that variants meet.
+    fn part_solution() -> impl Strategy<Value = PartSolution> {
+        let small = -1000_i128..1000_i128;
+
+        prop_oneof![
+            any::<i32>().prop_map(PartSolution::I32),
+            any::<u32>().prop_map(PartSolution::U32),
+            any::<i64>().prop_map(PartSolution::I64),
+            any::<u64>().prop_map(PartSolution::U64),
+            any::<i128>().prop_map(PartSolution::I128),
+            any::<u128>().prop_map(PartSolution::U128),
+            any::<isize>().prop_map(PartSolution::ISize),
+            any::<usize>().prop_map(PartSolution::USize),
+            small
+                .clone()
+                .prop_map(|integer| PartSolution::I32(i32::try_from(integer).unwrap())),
+            small
+                .clone()
+                .prop_map(|integer| PartSolution::I64(i64::try_from(integer).unwrap())),
+            small.clone().prop_map(PartSolution::I128),
+            small.prop_map(|integer| PartSolution::ISize(isize::try_from(integer).unwrap())),
+            (0_u32..2000).prop_map(PartSolution::U32),
+            (0_u64..2000).prop_map(PartSolution::U64),
+            (0_u128..2000).prop_map(PartSolution::U128),
+            (0_usize..2000).prop_map(PartSolution::USize),
+            Just(u64::MAX).prop_map(PartSolution::U64),
+            Just(u128::from(u64::MAX)).prop_map(PartSolution::U128),
+            Just(i128::MIN).prop_map(PartSolution::I128),
+            "[ab]{0,3}".prop_map(PartSolution::String),
+            collection::vec("[ab]{0,2}", 0..3).prop_map(PartSolution::Vec),
+            Just(()).prop_map(|()| PartSolution::Manual),
+            Just(()).prop_map(|()| PartSolution::None),
+        ]
     }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for u32 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(ref other) => self.partial_cmp(other),
-            PartSolution::I64(ref other) => i64::from(*self).partial_cmp(other),
-            PartSolution::U64(ref other) => u64::from(*self).partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into the smaller u32, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
+    proptest! {
+        #[test]
+        fn equality_is_symmetric(left in part_solution(), right in part_solution()) {
+            prop_assert_eq!(left == right, right == left);
         }
-    }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for u64 {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U32(other) => self.partial_cmp(&u64::from(other)),
-            PartSolution::I64(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is negative, so we are by definition Greater
-                    Some(Ordering::Greater)
-                }
-            },
-            PartSolution::U64(ref other) => self.partial_cmp(other),
-            PartSolution::USize(other) => {
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize, meaning self is Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
+        #[test]
+        fn ordering_is_antisymmetric(left in part_solution(), right in part_solution()) {
+            prop_assert_eq!(
+                left.partial_cmp(&right),
+                right.partial_cmp(&left).map(Ordering::reverse)
+            );
         }
-    }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for usize {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::I32(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U32(other) => {
-                // if this fails, that means that usize is smaller than u32
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::I64(other) => {
-                if other.is_negative() {
-                    Some(Ordering::Greater)
-                } else if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other is positive, but doesn't fit into usize, so we're Less
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::U64(other) => {
-                // if this fails, that means that usize is smaller than u64
-                if let Ok(other) = Self::try_from(other) {
-                    self.partial_cmp(&other)
-                } else {
-                    // other doesn't fit into usize
-                    Some(Ordering::Less)
-                }
-            },
-            PartSolution::USize(other) => other.partial_cmp(self),
-            PartSolution::ISize(_)
-            | PartSolution::String(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
+        #[test]
+        fn ordering_agrees_with_equality(left in part_solution(), right in part_solution()) {
+            prop_assert_eq!(left == right, left.partial_cmp(&right) == Some(Ordering::Equal));
         }
-    }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for String {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::String(ref s) => s.partial_cmp(self),
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::Vec(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
+        #[test]
+        fn ordering_is_transitive(
+            first in part_solution(),
+            second in part_solution(),
+            third in part_solution(),
+        ) {
+            if first <= second && second <= third {
+                prop_assert!(first <= third, "{:?} <= {:?} <= {:?}", first, second, third);
+            }
         }
-    }
-}
 
-impl std::cmp::PartialOrd<PartSolution> for Vec<String> {
-    fn partial_cmp(&self, other: &PartSolution) -> Option<Ordering> {
-        match *other {
-            PartSolution::Vec(ref v) => {
-                if v.len() != self.len() {
-                    return None;
-                }
+        #[test]
+        fn integers_compare_by_value(left in any::<i64>(), right in any::<u64>()) {
+            let expected = i128::from(left).cmp(&i128::from(right));
 
-                for (l, r) in self.iter().zip(v) {
-                    if l != r {
-                        return None;
-                    }
-                }
+            prop_assert_eq!(Some(expected), PartSolution::I64(left).partial_cmp(&PartSolution::U64(right)));
+            prop_assert_eq!(Some(expected), left.partial_cmp(&PartSolution::U64(right)));
+            prop_assert_eq!(Some(expected), PartSolution::I64(left).partial_cmp(&right));
+            prop_assert_eq!(Some(expected), PartSolution::I128(left.into()).partial_cmp(&u128::from(right)));
+        }
 
-                Some(Ordering::Equal)
-            },
-            PartSolution::I32(_)
-            | PartSolution::U32(_)
-            | PartSolution::I64(_)
-            | PartSolution::U64(_)
-            | PartSolution::ISize(_)
-            | PartSolution::USize(_)
-            | PartSolution::String(_)
-            | PartSolution::Manual
-            | PartSolution::None => None,
+        #[test]
+        fn primitives_compare_like_their_variant(left in any::<usize>(), right in part_solution()) {
+            prop_assert_eq!(PartSolution::USize(left).partial_cmp(&right), left.partial_cmp(&right));
+            prop_assert_eq!(PartSolution::USize(left) == right, left == right);
+            prop_assert_eq!(right.partial_cmp(&PartSolution::USize(left)), right.partial_cmp(&left));
         }
     }
+
+    #[test]
+    fn mixed_variants() {
+        assert_eq!(PartSolution::I32(5), PartSolution::U128(5));
+        assert_eq!(5_isize, PartSolution::U64(5));
+        assert_eq!(PartSolution::USize(5), 5_i64);
+        assert!(
+            PartSolution::I32(-1) < PartSolution::U128(u128::MAX),
+            "negative below the largest"
+        );
+        assert!(
+            PartSolution::U128(u128::MAX) > PartSolution::I128(i128::MAX),
+            "u128 beyond i128"
+        );
+        assert!(
+            PartSolution::String("b".to_owned()) > "a".to_owned(),
+            "text compares lexicographically"
+        );
+        assert!(
+            PartSolution::Vec(vec!["a".to_owned(), "b".to_owned()]) < vec!["b".to_owned()],
+            "lines compare lexicographically"
+        );
+        assert_eq!(
+            None,
+            PartSolution::I32(5).partial_cmp(&PartSolution::String("5".to_owned()))
+        );
+        assert_eq!(None, PartSolution::Manual.partial_cmp(&PartSolution::None));
+    }
 }
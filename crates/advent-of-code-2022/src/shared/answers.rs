@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use toml::de::Error as TomlError;
+
+use super::day::{Day, PuzzleId, Year};
+use super::error::SolveError;
+use super::input::DataDirs;
+use super::runner::Part;
+use super::{Integer, PartSolution};
+
+/// An answer as written in `data/<year>/answers.toml`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    /// An integer of any width, by value.
+    Integer(Integer),
+    Text(String),
+    /// Answers drawn on a screen, one string per line.
+    Lines(Vec<String>),
+}
+
+impl From<Answer> for PartSolution {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Integer(integer) => integer.into(),
+            Answer::Text(text) => PartSolution::String(text),
+            Answer::Lines(lines) => PartSolution::Vec(lines),
+        }
+    }
+}
+
+impl TryFrom<&PartSolution> for Answer {
+    type Error = ();
+
+    /// Fails for [`PartSolution::Manual`] and [`PartSolution::None`].
+    fn try_from(solution: &PartSolution) -> Result<Self, Self::Error> {
+        if let Some(integer) = solution.integer() {
+            return Ok(Answer::Integer(integer));
+        }
+
+        match *solution {
+            PartSolution::String(ref text) => Ok(Answer::Text(text.clone())),
+            PartSolution::Vec(ref lines) => Ok(Answer::Lines(lines.clone())),
+            PartSolution::I32(_)
+            | PartSolution::U32(_)
+            | PartSolution::I64(_)
+            | PartSolution::U64(_)
+            | PartSolution::I128(_)
+            | PartSolution::U128(_)
+            | PartSolution::ISize(_)
+            | PartSolution::USize(_)
+            | PartSolution::Manual
+            | PartSolution::None => Err(()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Answer::Integer(integer) => write!(f, "{}", integer),
+            Answer::Text(ref text) => f.write_str(text),
+            Answer::Lines(ref lines) => write!(f, "\n{}", lines.join("\n")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+/// The known answers of every day of a year, keyed by day, input set and part.
+///
+/// `answers.toml` holds those to the `inputs` set, the real input of every day. The answers to the examples are in the
+/// manifest of each day instead, `data/<year>/examples/<day>.toml`, see [`Manifest`](super::examples::Manifest).
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(Day, String, Part), PartSolution>,
+}
+
+impl Answers {
+    /// Loads `<year>/answers.toml` from the first of the [`DataDirs`] that has it.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be found or read, or is not a valid answers file.
+    pub fn load(year: Year) -> Result<Self, AnswersError> {
+        let contents = DataDirs::discover()
+            .and_then(|dirs| dirs.read(&year.path("answers.toml")))
+            .map_err(AnswersError::Read)?;
+
+        Self::parse(&contents)
+    }
+
+    /// Parses the contents of an answers file.
+    ///
+    /// # Errors
+    ///
+    /// When `contents` is not valid TOML, or a key is not a day, or a set holds anything but `part_1` and `part_2`.
+    pub fn parse(contents: &str) -> Result<Self, AnswersError> {
+        let raw: BTreeMap<String, BTreeMap<String, PartAnswers>> =
+            toml::from_str(contents).map_err(AnswersError::Toml)?;
+
+        let mut answers = BTreeMap::new();
+
+        for (day, sets) in raw {
+            let day: Day = day.parse().map_err(|_| AnswersError::Day(day))?;
+
+            for (set, parts) in sets {
+                for (part, answer) in [(Part::One, parts.part_1), (Part::Two, parts.part_2)] {
+                    if let Some(answer) = answer {
+                        answers.insert((day, set.clone(), part), answer.into());
+                    }
+                }
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// The known answer to `part` of `day` for the input `set`, if any.
+    #[must_use]
+    pub fn get(&self, day: Day, set: &str, part: Part) -> Option<&PartSolution> {
+        self.answers.get(&(day, set.to_owned(), part))
+    }
+
+    /// Checks `solution` against the known answer to `part` of `day` for the input `set`.
+    #[must_use]
+    pub fn verdict(&self, day: Day, set: &str, part: Part, solution: &PartSolution) -> Verdict<'_> {
+        match self.get(day, set, part) {
+            Some(expected) if expected == solution => Verdict::Correct,
+            Some(expected) => Verdict::Wrong { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// How a solution compares to the known answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict<'a> {
+    Correct,
+    Wrong {
+        expected: &'a PartSolution,
+    },
+    /// There is no known answer to compare with.
+    Unknown,
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+/// The answers of every year asked for so far, which live as long as the tests using them.
+static ANSWERS: LazyLock<Mutex<BTreeMap<Year, &'static Answers>>> = LazyLock::new(Mutex::default);
+
+/// The known answer to `part` of the puzzle `id` for the input `set`, if any, as used by the tests.
+///
+/// # Panics
+///
+/// When `data/<year>/answers.toml` cannot be loaded.
+#[must_use]
+pub fn answer(id: PuzzleId, set: &str, part: Part) -> Option<&'static PartSolution> {
+    let answers = *ANSWERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(id.year)
+        .or_insert_with(|| {
+            let answers = Answers::load(id.year).unwrap_or_else(|error| panic!("{}", error));
+
+            Box::leak(Box::new(answers))
+        });
+
+    answers.get(id.day, set, part)
+}
+
+/// An error which can be returned when loading an answers file.
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(SolveError),
+    Toml(TomlError),
+    /// A key at the top level is not a [`Day`].
+    Day(String),
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AnswersError::Read(ref source) => Some(source),
+            AnswersError::Toml(ref source) => Some(source),
+            AnswersError::Day(_) => None,
+        }
+    }
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            AnswersError::Read(ref source) => write!(f, "could not load answers: {}", source),
+            AnswersError::Toml(ref source) => write!(f, "invalid answers file: {}", source),
+            AnswersError::Day(ref day) => write!(f, "invalid answers file: `{}` is not a day", day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::{Strategy, any, prop_oneof, proptest};
+    use proptest::prop_assert_eq;
+
+    use crate::day;
+    use crate::shared::PartSolution;
+    use crate::shared::answers::{Answer, Answers, AnswersError, Verdict};
+    use crate::shared::runner::Part;
+
+    const ANSWERS: &str = r##"
+[05.inputs]
+part_1 = "VJSFHWGFT"
+
+[10.examples]
+part_1 = 13_140
+part_2 = [".##.", "#.#."]
+"##;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            Some(&PartSolution::String("VJSFHWGFT".into())),
+            answers.get(day!(5), "inputs", Part::One)
+        );
+        assert_eq!(None, answers.get(day!(5), "inputs", Part::Two));
+        assert_eq!(
+            Some(&PartSolution::Vec(vec![".##.".into(), "#.#.".into()])),
+            answers.get(day!(10), "examples", Part::Two)
+        );
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            Verdict::Correct,
+            answers.verdict(day!(10), "examples", Part::One, &PartSolution::I32(13_140))
+        );
+        assert_eq!(
+            "wrong, expected 13140",
+            answers
+                .verdict(day!(10), "examples", Part::One, &PartSolution::USize(1))
+                .to_string()
+        );
+        assert_eq!(
+            Verdict::Unknown,
+            answers.verdict(day!(10), "inputs", Part::One, &PartSolution::USize(1))
+        );
+    }
+
+    #[test]
+    fn beyond_i64() {
+        let answers = Answers::parse(
+            "[01.inputs]\npart_1 = \"340282366920938463463374607431768211455\"\npart_2 = \"0012\"",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Verdict::Correct,
+            answers.verdict(day!(1), "inputs", Part::One, &PartSolution::U128(u128::MAX))
+        );
+        assert_eq!(
+            Some(&PartSolution::String("0012".into())),
+            answers.get(day!(1), "inputs", Part::Two),
+            "digits TOML could hold as an integer stay text"
+        );
+    }
+
+    /// Any integer [`PartSolution`], from the smallest [`i128`] to the largest [`u128`].
+    fn integer() -> impl Strategy<Value = PartSolution> {
+        prop_oneof![
+            any::<i32>().prop_map(PartSolution::I32),
+            any::<i64>().prop_map(PartSolution::I64),
+            any::<u64>().prop_map(PartSolution::U64),
+            any::<i128>().prop_map(PartSolution::I128),
+            any::<u128>().prop_map(PartSolution::U128),
+            any::<usize>().prop_map(PartSolution::USize),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn integers_round_trip(solution in integer()) {
+            let answer = Answer::try_from(&solution).unwrap();
+            let written = toml::to_string(&BTreeMap::from([("part_1", &answer)])).unwrap();
+            let read = toml::from_str::<BTreeMap<String, Answer>>(&written).unwrap();
+
+            prop_assert_eq!(&answer, &read["part_1"]);
+            prop_assert_eq!(answer.to_string(), solution.to_string());
+            prop_assert_eq!(PartSolution::from(answer), solution);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(
+            matches!(Answers::parse("[26.inputs]"), Err(AnswersError::Day(_))),
+            "there is no day 26"
+        );
+        assert!(
+            matches!(
+                Answers::parse("[01.inputs]\npart_3 = 1"),
+                Err(AnswersError::Toml(_))
+            ),
+            "there is no part 3"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use toml::de::Error as TomlError;
+use toml::ser::Error as TomlSerError;
+
+/// The median timings of a benchmark run, saved so that a later run can be compared against it.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Baseline {
+    /// The median of every benchmark in nanoseconds, keyed by its name, like `2022/day15/part_1`.
+    timings: BTreeMap<String, u64>,
+}
+
+impl Baseline {
+    /// Records `median` as the timing of the benchmark `name`.
+    pub fn insert(&mut self, name: &str, median: Duration) {
+        let nanos = u64::try_from(median.as_nanos()).unwrap_or(u64::MAX);
+
+        self.timings.insert(name.to_owned(), nanos);
+    }
+
+    /// The timing of the benchmark `name`, if it has one.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.timings.get(name).copied().map(Duration::from_nanos)
+    }
+
+    /// Loads the baseline saved at `path`.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be read, or is not a baseline.
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents = fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        toml::from_str(&contents).map_err(BaselineError::Toml)
+    }
+
+    /// Saves this baseline at `path`, creating its directory when needed.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let contents = toml::to_string(self).map_err(BaselineError::Serialize)?;
+
+        let io = |source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+
+        fs::write(path, contents).map_err(io)
+    }
+
+    /// Every benchmark of `current` which is more than `threshold` percent slower than in this baseline. Benchmarks
+    /// which are in only one of them are left out.
+    #[must_use]
+    pub fn regressions(&self, current: &Baseline, threshold: u32) -> Vec<Regression> {
+        current
+            .timings
+            .iter()
+            .filter_map(|(name, &nanos)| {
+                let baseline = *self.timings.get(name)?;
+
+                // in integers, as `nanos > baseline * (1 + threshold / 100)`
+                let limit = u128::from(baseline) * (100 + u128::from(threshold));
+
+                (u128::from(nanos) * 100 > limit).then(|| Regression {
+                    name: name.clone(),
+                    baseline: Duration::from_nanos(baseline),
+                    current: Duration::from_nanos(nanos),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A benchmark that got slower than its baseline allows.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub name: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// How many percent slower the benchmark got, rounded down.
+    #[must_use]
+    pub fn percent(&self) -> u128 {
+        let baseline = self.baseline.as_nanos().max(1);
+
+        (self.current.as_nanos().saturating_sub(baseline)) * 100 / baseline
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {:.2?} -> {:.2?} (+{}%)",
+            self.name,
+            self.baseline,
+            self.current,
+            self.percent()
+        )
+    }
+}
+
+/// An error which can be returned when loading or saving a [`Baseline`].
+#[derive(Debug)]
+pub enum BaselineError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Toml(TomlError),
+    Serialize(TomlSerError),
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BaselineError::Io { ref source, .. } => Some(source),
+            BaselineError::Toml(ref source) => Some(source),
+            BaselineError::Serialize(ref source) => Some(source),
+        }
+    }
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BaselineError::Io {
+                ref path,
+                ref source,
+            } => write!(f, "could not access \"{}\": {}", path.display(), source),
+            BaselineError::Toml(ref source) => write!(f, "invalid baseline: {}", source),
+            BaselineError::Serialize(ref source) => {
+                write!(f, "could not write the baseline: {}", source)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::baseline::{Baseline, Regression};
+    use crate::shared::testing::temp_path;
+
+    #[test]
+    fn regressions() {
+        let mut baseline = Baseline::default();
+        baseline.insert("2022/day15/part_1", Duration::from_millis(100));
+        baseline.insert("2022/day15/part_2", Duration::from_millis(100));
+        baseline.insert("2022/day16/part_1", Duration::from_millis(100));
+
+        let mut current = Baseline::default();
+        current.insert("2022/day15/part_1", Duration::from_millis(110));
+        current.insert("2022/day15/part_2", Duration::from_millis(111));
+        current.insert("2022/day16/part_1", Duration::from_millis(50));
+        current.insert("2022/day17/part_1", Duration::from_millis(500));
+
+        let regressions = baseline.regressions(&current, 10);
+
+        assert_eq!(
+            vec![Regression {
+                name: "2022/day15/part_2".to_owned(),
+                baseline: Duration::from_millis(100),
+                current: Duration::from_millis(111),
+            }],
+            regressions
+        );
+        assert_eq!(
+            "2022/day15/part_2: 100.00ms -> 111.00ms (+11%)",
+            regressions[0].to_string()
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = temp_path("baseline").join("main.toml");
+
+        let mut baseline = Baseline::default();
+        baseline.insert("2022/day01/parse", Duration::from_nanos(1234));
+
+        baseline.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path).unwrap();
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(baseline, loaded);
+        assert_eq!(
+            Some(Duration::from_nanos(1234)),
+            loaded.get("2022/day01/parse")
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use super::day::Day;
+use super::diagnostics::{Event, Level, Progress, Reporter, Silent};
+use super::error::SolveError;
+use super::runner::Part;
+use super::visualize::{Recording, Visualize, Visualizer};
+
+/// A value the puzzle fixes for the real input, but which the examples set differently, like the row to look at.
+#[derive(Debug, Clone, Copy)]
+pub struct Param<T> {
+    /// The name it is overridden by, as in `--param row=10`.
+    pub name: &'static str,
+    /// The value for the real input.
+    pub default: T,
+}
+
+impl<T> Param<T> {
+    #[must_use]
+    pub const fn new(name: &'static str, default: T) -> Self {
+        Self { name, default }
+    }
+
+    /// This parameter as a day declares it, so that overrides are checked to be a valid `T`.
+    #[must_use]
+    pub fn declare(&self) -> DeclaredParam
+    where
+        T: FromStr + Display,
+    {
+        DeclaredParam {
+            name: self.name,
+            default: self.default.to_string(),
+            accepts: |value| value.parse::<T>().is_ok(),
+        }
+    }
+}
+
+impl Param<&'static str> {
+    /// This text parameter as a day declares it, which any override is valid for.
+    #[must_use]
+    pub fn declare_str(&self) -> DeclaredParam {
+        DeclaredParam {
+            name: self.name,
+            default: self.default.to_owned(),
+            accepts: |_| true,
+        }
+    }
+}
+
+/// A [`Param`] a day reads, with its type erased, as listed by [`TryParts::params`](super::TryParts::params).
+#[derive(Debug, Clone)]
+pub struct DeclaredParam {
+    /// The name it is overridden by.
+    name: &'static str,
+    /// The value for the real input, as text.
+    default: String,
+    /// Whether an overriding value parses as the type of the parameter.
+    accepts: fn(&str) -> bool,
+}
+
+/// What a part is solved with besides its input: the overridden puzzle parameters, where to report its progress
+/// and diagnostics, and how to visualize its simulation.
+#[derive(Clone)]
+pub struct SolveContext {
+    params: BTreeMap<String, String>,
+    reporter: Arc<dyn Reporter>,
+    visualizer: Option<Visualizer>,
+    /// The frames of the part being solved, set by [`SolveContext::for_part`] when visualizing.
+    recording: Option<Arc<Recording>>,
+}
+
+impl Default for SolveContext {
+    /// No overridden parameters, and a [`Silent`] reporter.
+    fn default() -> Self {
+        Self {
+            params: BTreeMap::new(),
+            reporter: Arc::new(Silent {}),
+            visualizer: None,
+            recording: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SolveContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SolveContext")
+            .field("params", &self.params)
+            .field("verbosity", &self.reporter.verbosity())
+            .field("visualizer", &self.visualizer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SolveContext {
+    /// This context, reporting to `reporter`.
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Arc<dyn Reporter>) -> Self {
+        self.reporter = reporter;
+
+        self
+    }
+
+    /// This context, visualizing simulations with `visualizer`.
+    #[must_use]
+    pub fn with_visualizer(mut self, visualizer: Visualizer) -> Self {
+        self.visualizer = Some(visualizer);
+
+        self
+    }
+
+    /// This context, for solving `part` of `day`, with a recording of its own when visualizing.
+    #[must_use]
+    pub fn for_part(&self, day: Day, part: Part) -> Self {
+        Self {
+            recording: self
+                .visualizer
+                .clone()
+                .map(|visualizer| Arc::new(Recording::new(visualizer, day, part))),
+            ..self.clone()
+        }
+    }
+
+    /// Whether frames are recorded, for simulations that have to prepare before they can be drawn.
+    #[must_use]
+    pub fn is_visualizing(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Records a frame of `scene`, only drawing it when visualizing.
+    ///
+    /// A failure to record is reported, and ends the recording rather than the solution.
+    pub fn visualize<V: Visualize + ?Sized>(&self, scene: &V) {
+        if let Some(ref recording) = self.recording
+            && let Err(error) = recording.record(scene)
+        {
+            self.report(Level::Info, || format!("Cannot visualize: {}", error));
+        }
+    }
+
+    /// Reports the `message` at `level`, only making it when the reporter shows that level.
+    pub fn report<M: FnOnce() -> String>(&self, level: Level, message: M) {
+        if self.reporter.verbosity().shows(level) {
+            self.reporter.report(Event::Message {
+                level,
+                text: message(),
+            });
+        }
+    }
+
+    /// Reports the progress of the task `label`, which takes `total` steps.
+    #[must_use]
+    pub fn progress(&self, label: &str, total: usize) -> Progress<'_> {
+        Progress::new(self.reporter.as_ref(), label, total)
+    }
+
+    /// This context, with the parameter `name` overridden by `value`.
+    #[must_use]
+    pub fn with_param<V: ToString + ?Sized>(mut self, name: &str, value: &V) -> Self {
+        self.params.insert(name.to_owned(), value.to_string());
+
+        self
+    }
+
+    /// Checks that every overridden parameter is one of those `declared`, with a value of its type, so that
+    /// [`SolveContext::get`] cannot fail.
+    ///
+    /// # Errors
+    ///
+    /// [`SolveError::Param`] for the first override that is unknown or invalid.
+    pub fn check_params(&self, declared: &[DeclaredParam]) -> Result<(), SolveError> {
+        for (name, value) in self.params() {
+            let error = |message: String| SolveError::Param {
+                name: name.to_owned(),
+                message,
+            };
+
+            let Some(param) = declared.iter().find(|param| param.name == name) else {
+                let names = declared
+                    .iter()
+                    .map(|param| format!("`{}`", param.name))
+                    .collect::<Vec<_>>();
+
+                return Err(error(if names.is_empty() {
+                    "the day has no parameters".to_owned()
+                } else {
+                    format!("unknown, expecting one of {}", names.join(", "))
+                }));
+            };
+
+            if !(param.accepts)(value) {
+                return Err(error(format!(
+                    "cannot be `{}`, expecting a value like `{}`",
+                    value, param.default
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The value of `param`, which is its default unless it was overridden.
+    ///
+    /// # Panics
+    ///
+    /// When the overriding value is not a valid `T`, which [`SolveContext::check_params`] rules out.
+    #[must_use]
+    pub fn get<T: FromStr + Clone>(&self, param: &Param<T>) -> T {
+        match self.params.get(param.name) {
+            Some(value) => value.parse().unwrap_or_else(|_| {
+                panic!(
+                    "Parameter `{}` cannot be `{}`, expecting a {}",
+                    param.name,
+                    value,
+                    std::any::type_name::<T>()
+                )
+            }),
+            None => param.default.clone(),
+        }
+    }
+
+    /// The value of the text `param`, which is its default unless it was overridden.
+    #[must_use]
+    pub fn get_str<'a>(&'a self, param: &Param<&'a str>) -> &'a str {
+        self.params
+            .get(param.name)
+            .map_or(param.default, String::as_str)
+    }
+
+    /// Every overridden parameter, with its value.
+    pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+impl FromIterator<(String, String)> for SolveContext {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self {
+            params: iter.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Display for SolveContext {
+    /// The overridden parameters as `name=value`, separated by commas.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (name, value)) in self.params().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{}={}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a parameter override like `row=10`, as given on the command line.
+///
+/// # Errors
+///
+/// When there is no `=`, or nothing before it.
+pub fn parse_param(s: &str) -> Result<(String, String), ParamFromStrError> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_owned(), value.trim().to_owned()))
+        },
+        _ => Err(ParamFromStrError {}),
+    }
+}
+
+/// An error which can be returned when parsing a parameter override with [`parse_param`].
+#[derive(Debug)]
+pub struct ParamFromStrError {}
+
+impl Error for ParamFromStrError {}
+
+impl Display for ParamFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a parameter like `row=10`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::context::{Param, SolveContext, parse_param};
+    use crate::shared::diagnostics::{Event, Level, Recorder, Verbosity};
+
+    const ROW: Param<isize> = Param::new("row", 2_000_000);
+    const START: Param<&str> = Param::new("start", "AA");
+
+    #[test]
+    fn defaults_and_overrides() {
+        let context = SolveContext::default();
+
+        assert_eq!(2_000_000, context.get(&ROW));
+        assert_eq!("AA", context.get_str(&START));
+
+        let context = context.with_param("row", &10);
+
+        assert_eq!(10, context.get(&ROW));
+        assert_eq!("row=10", context.to_string());
+
+        let context = context.with_param("start", "BB");
+
+        assert_eq!("BB", context.get_str(&START));
+    }
+
+    #[test]
+    #[should_panic(expected = "Parameter `row` cannot be `ten`, expecting a isize")]
+    fn invalid_override() {
+        let context = SolveContext::default().with_param("row", "ten");
+
+        assert_eq!(10, context.get(&ROW));
+    }
+
+    #[test]
+    fn checks_overrides() {
+        let declared = [ROW.declare(), START.declare_str()];
+
+        let check = |name: &str, value: &str| {
+            SolveContext::default()
+                .with_param(name, value)
+                .check_params(&declared)
+                .map_err(|error| error.to_string())
+        };
+
+        assert_eq!(Ok(()), check("row", "10"));
+        assert_eq!(Ok(()), check("start", "BB"));
+        assert_eq!(
+            Err(
+                "invalid parameter `row`: cannot be `ten`, expecting a value like `2000000`"
+                    .to_owned()
+            ),
+            check("row", "ten")
+        );
+        assert_eq!(
+            Err("invalid parameter `rwo`: unknown, expecting one of `row`, `start`".to_owned()),
+            check("rwo", "10")
+        );
+        assert_eq!(
+            Err("invalid parameter `row`: the day has no parameters".to_owned()),
+            SolveContext::default()
+                .with_param("row", &10)
+                .check_params(&[])
+                .map_err(|error| error.to_string())
+        );
+    }
+
+    #[test]
+    fn reports() {
+        let recorder = Arc::new(Recorder::new(Verbosity::Verbose));
+        let context = SolveContext::default().with_reporter(Arc::<Recorder>::clone(&recorder));
+
+        context.report(Level::Debug, || "shown".to_owned());
+        context.report(Level::Trace, || {
+            unreachable!("hidden messages are not made")
+        });
+
+        assert_eq!(
+            vec![Event::Message {
+                level: Level::Debug,
+                text: "shown".to_owned()
+            }],
+            recorder.events()
+        );
+
+        // the default reporter is silent
+        SolveContext::default().report(Level::Info, || unreachable!("nothing is shown"));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(("row".to_owned(), "10".to_owned())),
+            parse_param(" row = 10").map_err(drop)
+        );
+        assert!(parse_param("row").is_err(), "there is no value");
+        assert!(parse_param("=10").is_err(), "there is no name");
+    }
+}
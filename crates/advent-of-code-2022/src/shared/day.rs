@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
@@ -83,6 +84,125 @@ impl Display for DayFromStrError {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+
+/// The year the solutions in this repository are for, unless they say otherwise.
+pub const DEFAULT_YEAR: Year = Year(2022);
+
+/// A year of advent, from the first one in 2015 onwards.
+///
+/// ```
+/// # use advent_of_code_2022::shared::day::Year;
+/// assert_eq!(Year::try_new(2014), None);
+/// assert_eq!(Year::try_new(2022).unwrap().to_string(), "2022");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's 2015 or later, returns [`None`] otherwise.
+    #[must_use]
+    pub const fn try_new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+
+        Some(Year(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    #[must_use]
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The path of `relative` in this year, relative to a data directory, e.g. `2022/answers.toml`.
+    #[must_use]
+    pub fn path(self, relative: &str) -> PathBuf {
+        Path::new(&self.to_string()).join(relative)
+    }
+}
+
+impl Default for Year {
+    fn default() -> Self {
+        DEFAULT_YEAR
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError {})?;
+        Self::try_new(year).ok_or(YearFromStrError {})
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError {}
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year from 2015 on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single puzzle: a [`Day`] of a [`Year`].
+///
+/// # Display
+/// This value displays as the year and the day, e.g. `2022 day 08`.
+///
+/// ```
+/// # use advent_of_code_2022::shared::day::{Day, PuzzleId, Year};
+/// let id = PuzzleId::new(Year::try_new(2015).unwrap(), Day::try_new(8).unwrap());
+/// assert_eq!(id.to_string(), "2015 day 08");
+/// assert_eq!(id.path("inputs"), std::path::Path::new("2015/inputs/08.txt"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    #[must_use]
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// The path of this puzzle's file in `folder`, relative to a data directory, e.g. `2022/inputs/08.txt`.
+    #[must_use]
+    pub fn path(&self, folder: &str) -> PathBuf {
+        self.year.path(folder).join(format!("{}.txt", self.day))
+    }
+}
+
+/// A day of the [`DEFAULT_YEAR`].
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(DEFAULT_YEAR, day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a `const` context.
 #[macro_export]
 macro_rules! day {
@@ -94,3 +214,15 @@ macro_rules! day {
         ))
     }};
 }
+
+/// Creates a [`Year`] value in a `const` context.
+#[macro_export]
+macro_rules! year {
+    ($year:literal) => {{
+        $crate::shared::day::Year::try_new($year).expect(concat!(
+            "invalid year `",
+            $year,
+            "`, expecting 2015 or later"
+        ))
+    }};
+}
@@ -0,0 +1,380 @@
+use std::io::{IsTerminal as _, Write as _, stderr};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How much a solution has to say about a [`Event::Message`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Worth knowing on every run.
+    Info,
+    /// Helps to understand what the solution does, like a drawing of its state.
+    Debug,
+    /// Follows the solution step by step.
+    Trace,
+}
+
+/// How much a [`Reporter`] wants to hear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// Nothing at all, not even progress.
+    Quiet,
+    /// Progress and [`Level::Info`] messages.
+    #[default]
+    Normal,
+    /// Up to [`Level::Debug`] messages.
+    Verbose,
+    /// Every message.
+    Trace,
+}
+
+impl Verbosity {
+    /// The verbosity of `-v` given `count` times, or of `-q`.
+    #[must_use]
+    pub fn from_flags(verbose: u8, quiet: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Trace,
+        }
+    }
+
+    /// Whether messages of `level` are shown.
+    #[must_use]
+    pub fn shows(self, level: Level) -> bool {
+        match self {
+            Verbosity::Quiet => false,
+            Verbosity::Normal => level == Level::Info,
+            Verbosity::Verbose => level != Level::Trace,
+            Verbosity::Trace => true,
+        }
+    }
+}
+
+/// Something a solution reports while solving a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Message {
+        level: Level,
+        text: String,
+    },
+    /// `done` out of `total` steps of the task `label` are done.
+    Progress {
+        label: String,
+        done: usize,
+        total: usize,
+    },
+    /// The task `label` ended, whether or not all of its steps were done.
+    Finished {
+        label: String,
+    },
+}
+
+impl Event {
+    /// The level the event is reported at, progress being [`Level::Info`].
+    #[must_use]
+    pub fn level(&self) -> Level {
+        match *self {
+            Event::Message { level, .. } => level,
+            Event::Progress { .. } | Event::Finished { .. } => Level::Info,
+        }
+    }
+}
+
+/// Where the [`Event`]s of a solution go.
+///
+/// Reporters are shared between the worker threads of [`run_parallel`](super::pool::run_parallel).
+pub trait Reporter: Send + Sync {
+    /// How much this reporter wants to hear.
+    fn verbosity(&self) -> Verbosity;
+
+    /// Handles `event`, which is only sent when [`Reporter::verbosity`] shows its level.
+    fn report(&self, event: Event);
+}
+
+/// Drops every event, so that tests and `aoc run` stay clean.
+#[derive(Debug, Default)]
+pub struct Silent {}
+
+impl Reporter for Silent {
+    fn verbosity(&self) -> Verbosity {
+        Verbosity::Quiet
+    }
+
+    fn report(&self, _event: Event) {}
+}
+
+/// Writes messages to stderr, and draws progress bars there when it is a terminal.
+#[derive(Debug)]
+pub struct Terminal {
+    verbosity: Verbosity,
+    bars: bool,
+    /// Whether a progress bar is on the last line, which a message has to start below.
+    drawing: AtomicBool,
+}
+
+impl Terminal {
+    #[must_use]
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self {
+            verbosity,
+            bars: stderr().is_terminal(),
+            drawing: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Reporter for Terminal {
+    fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    fn report(&self, event: Event) {
+        let mut lock = stderr().lock();
+
+        // nothing can be done when stderr is gone, and the solution should not fail over it
+        let _unused = match event {
+            Event::Message { ref text, .. } => {
+                let newline = if self.drawing.swap(false, Ordering::Relaxed) {
+                    "\n"
+                } else {
+                    ""
+                };
+
+                writeln!(lock, "{}{}", newline, text)
+            },
+            Event::Progress {
+                ref label,
+                done,
+                total,
+            } if self.bars => {
+                self.drawing.store(true, Ordering::Relaxed);
+
+                write!(lock, "\r{}", progress_bar(label, done, total))
+            },
+            Event::Finished { .. } if self.drawing.swap(false, Ordering::Relaxed) => {
+                writeln!(lock)
+            },
+            Event::Progress { .. } | Event::Finished { .. } => Ok(()),
+        };
+    }
+}
+
+/// Keeps every event, for tests to look at.
+#[derive(Debug)]
+pub struct Recorder {
+    verbosity: Verbosity,
+    events: Mutex<Vec<Event>>,
+}
+
+impl Recorder {
+    #[must_use]
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self {
+            verbosity,
+            events: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The events reported so far, in order.
+    ///
+    /// # Panics
+    ///
+    /// When a thread panicked while reporting.
+    #[must_use]
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().expect("Recorder poisoned").clone()
+    }
+}
+
+impl Reporter for Recorder {
+    fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    fn report(&self, event: Event) {
+        self.events.lock().expect("Recorder poisoned").push(event);
+    }
+}
+
+/// Reports the progress of a task to a [`Reporter`], once per percent, and that it finished when dropped.
+pub struct Progress<'a> {
+    reporter: &'a dyn Reporter,
+    label: String,
+    total: usize,
+    /// The last percentage reported, if any.
+    percent: Option<usize>,
+}
+
+impl<'a> Progress<'a> {
+    /// The progress of the task `label`, which takes `total` steps.
+    #[must_use]
+    pub fn new(reporter: &'a dyn Reporter, label: &str, total: usize) -> Self {
+        Self {
+            reporter,
+            label: label.to_owned(),
+            total,
+            percent: None,
+        }
+    }
+
+    /// Records that `done` steps are done, reporting it when that is another percent.
+    pub fn set(&mut self, done: usize) {
+        if !self.reporter.verbosity().shows(Level::Info) {
+            return;
+        }
+
+        let percent = percent(done, self.total);
+
+        if self.percent != Some(percent) {
+            self.percent = Some(percent);
+
+            self.reporter.report(Event::Progress {
+                label: self.label.clone(),
+                done,
+                total: self.total,
+            });
+        }
+    }
+}
+
+impl Drop for Progress<'_> {
+    fn drop(&mut self) {
+        if self.percent.is_some() {
+            self.reporter.report(Event::Finished {
+                label: self.label.clone(),
+            });
+        }
+    }
+}
+
+/// How many percent `done` is of `total`, at most 100.
+fn percent(done: usize, total: usize) -> usize {
+    let done = done.min(total);
+
+    match done.checked_mul(100) {
+        _ if total == 0 => 100,
+        Some(scaled) => scaled / total,
+        // a task this long has at least 100 steps
+        None => done / (total / 100),
+    }
+}
+
+/// The width of a progress bar, between its brackets.
+const BAR_WIDTH: usize = 30;
+
+/// Draws `done` out of `total` steps of `label`, like `lines [=======>      ] 25%`.
+fn progress_bar(label: &str, done: usize, total: usize) -> String {
+    let percent = percent(done, total);
+    let filled = percent * BAR_WIDTH / 100;
+
+    let head = if filled < BAR_WIDTH { ">" } else { "" };
+    let blank = BAR_WIDTH - filled - head.len();
+
+    format!(
+        "{} [{}{}{}] {:>3}%",
+        label,
+        "=".repeat(filled),
+        head,
+        " ".repeat(blank),
+        percent
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::diagnostics::{
+        Event, Level, Progress, Recorder, Reporter as _, Verbosity, progress_bar,
+    };
+
+    #[test]
+    fn verbosity() {
+        assert_eq!(Verbosity::Quiet, Verbosity::from_flags(2, true));
+        assert_eq!(Verbosity::Normal, Verbosity::from_flags(0, false));
+        assert_eq!(Verbosity::Verbose, Verbosity::from_flags(1, false));
+        assert_eq!(Verbosity::Trace, Verbosity::from_flags(3, false));
+
+        assert!(!Verbosity::Quiet.shows(Level::Info), "quiet shows nothing");
+        assert!(Verbosity::Normal.shows(Level::Info), "normal shows info");
+        assert!(!Verbosity::Normal.shows(Level::Debug), "normal hides debug");
+        assert!(
+            Verbosity::Verbose.shows(Level::Debug),
+            "verbose shows debug"
+        );
+        assert!(
+            !Verbosity::Verbose.shows(Level::Trace),
+            "verbose hides trace"
+        );
+        assert!(
+            Verbosity::Trace.shows(Level::Trace),
+            "trace shows everything"
+        );
+    }
+
+    #[test]
+    fn progress_once_per_percent() {
+        let recorder = Recorder::new(Verbosity::Normal);
+
+        {
+            let mut progress = Progress::new(&recorder, "lines", 1000);
+
+            for done in 0..=1000 {
+                progress.set(done);
+            }
+        }
+
+        let events = recorder.events();
+
+        assert_eq!(102, events.len());
+        assert_eq!(
+            Some(&Event::Progress {
+                label: "lines".to_owned(),
+                done: 10,
+                total: 1000
+            }),
+            events.get(1)
+        );
+        assert_eq!(
+            Some(&Event::Finished {
+                label: "lines".to_owned()
+            }),
+            events.last()
+        );
+    }
+
+    #[test]
+    fn quiet_progress() {
+        let recorder = Recorder::new(Verbosity::Quiet);
+
+        {
+            let mut progress = Progress::new(&recorder, "lines", 10);
+
+            progress.set(5);
+        }
+
+        assert_eq!(Vec::<Event>::new(), recorder.events());
+        assert_eq!(Verbosity::Quiet, recorder.verbosity());
+    }
+
+    #[test]
+    fn progress_bars() {
+        assert_eq!(
+            "lines [>                             ]   0%",
+            progress_bar("lines", 0, 4)
+        );
+        assert_eq!(
+            "lines [=======>                      ]  25%",
+            progress_bar("lines", 1, 4)
+        );
+        assert_eq!(
+            "lines [==============================] 100%",
+            progress_bar("lines", 4, 4)
+        );
+        assert_eq!(
+            "empty [==============================] 100%",
+            progress_bar("empty", 0, 0)
+        );
+    }
+}
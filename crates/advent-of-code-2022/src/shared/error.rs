@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// Why a day could not be solved.
+#[derive(Debug)]
+pub enum SolveError {
+    /// The input could not be read.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The input is in none of the places it was looked for.
+    NotFound { tried: Vec<PathBuf> },
+    /// The config file is invalid.
+    Config { path: PathBuf, message: String },
+    /// A puzzle parameter is overridden although the day does not declare it, or with a value it cannot take.
+    Param { name: String, message: String },
+    /// The input is malformed. Both `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but violates an assumption the puzzle makes about it.
+    Assumption(String),
+    /// The search space was exhausted without finding an answer.
+    NoSolution(String),
+    /// The solution panicked with this message.
+    Panicked(String),
+}
+
+impl SolveError {
+    /// Creates a [`SolveError::Parse`] at the given 1-based `line` and `column`.
+    pub fn parse<M: Into<String>>(line: usize, column: usize, message: M) -> Self {
+        SolveError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [`SolveError::Parse`] at the character `index` (0-based) of the line `line_index` (0-based).
+    pub fn parse_at<M: Into<String>>(line_index: usize, index: usize, message: M) -> Self {
+        SolveError::parse(line_index + 1, index + 1, message)
+    }
+
+    /// Creates a [`SolveError::Assumption`].
+    pub fn assumption<M: Into<String>>(message: M) -> Self {
+        SolveError::Assumption(message.into())
+    }
+
+    /// Creates a [`SolveError::NoSolution`].
+    pub fn no_solution<M: Into<String>>(message: M) -> Self {
+        SolveError::NoSolution(message.into())
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SolveError::Io { ref source, .. } => Some(source),
+            SolveError::NotFound { .. }
+            | SolveError::Config { .. }
+            | SolveError::Param { .. }
+            | SolveError::Parse { .. }
+            | SolveError::Assumption(_)
+            | SolveError::NoSolution(_)
+            | SolveError::Panicked(_) => None,
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SolveError::Io {
+                ref path,
+                ref source,
+            } => write!(f, "could not read \"{}\": {}", path.display(), source),
+            SolveError::NotFound { ref tried } => {
+                f.write_str("not found, tried ")?;
+
+                for (index, path) in tried.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "\"{}\"", path.display())?;
+                }
+
+                Ok(())
+            },
+            SolveError::Config {
+                ref path,
+                ref message,
+            } => write!(f, "invalid config \"{}\": {}", path.display(), message),
+            SolveError::Param {
+                ref name,
+                ref message,
+            } => write!(f, "invalid parameter `{}`: {}", name, message),
+            SolveError::Parse {
+                line,
+                column,
+                ref message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            SolveError::Assumption(ref message) => {
+                write!(f, "puzzle assumption violated: {}", message)
+            },
+            SolveError::NoSolution(ref message) => write!(f, "no solution found: {}", message),
+            SolveError::Panicked(ref message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::error::SolveError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "parse error at line 3, column 1: expected `A`, `B` or `C`, found `Q`",
+            SolveError::parse_at(2, 0, "expected `A`, `B` or `C`, found `Q`").to_string()
+        );
+
+        assert_eq!(
+            "puzzle assumption violated: no start `S` in the height map",
+            SolveError::assumption("no start `S` in the height map").to_string()
+        );
+
+        assert_eq!(
+            "no solution found: the goal is unreachable",
+            SolveError::no_solution("the goal is unreachable").to_string()
+        );
+    }
+}
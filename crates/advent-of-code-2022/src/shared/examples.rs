@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use toml::de::Error as TomlError;
+
+use super::PartSolution;
+use super::answers::Answer;
+use super::context::SolveContext;
+use super::day::PuzzleId;
+use super::error::SolveError;
+use super::input::DataDirs;
+use super::runner::Part;
+
+/// A puzzle parameter as written in a manifest.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Param {
+    Integer(i64),
+    Text(String),
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Param::Integer(integer) => write!(f, "{}", integer),
+            Param::Text(ref text) => f.write_str(text),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawExample {
+    file: String,
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+    #[serde(default)]
+    params: BTreeMap<String, Param>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawManifest {
+    examples: Vec<RawExample>,
+}
+
+/// An example of a puzzle, as listed in its manifest.
+#[derive(Debug)]
+pub struct Example {
+    /// The input set it is known by, `examples` for `08.txt`, or `examples-2` for `18-2.txt`.
+    pub set: String,
+    /// The file holding the example, in the `examples` folder.
+    pub file: String,
+    pub part_1: Option<PartSolution>,
+    pub part_2: Option<PartSolution>,
+    /// The puzzle parameters the example is solved with, rather than those of the real input.
+    pub params: BTreeMap<String, String>,
+}
+
+impl Example {
+    /// The known answer to `part` of this example, if any.
+    #[must_use]
+    pub fn answer(&self, part: Part) -> Option<&PartSolution> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    /// The parameter `name` of this example, if it has it and it parses as a `T`.
+    #[must_use]
+    pub fn param<T: FromStr>(&self, name: &str) -> Option<T> {
+        let value = self.params.get(name)?;
+
+        value.parse().ok()
+    }
+
+    /// The context to solve this example in, with its puzzle parameters.
+    #[must_use]
+    pub fn context(&self) -> SolveContext {
+        self.params.clone().into_iter().collect()
+    }
+
+    /// Where the example of the puzzle `id` is, relative to a data directory.
+    #[must_use]
+    pub fn path(&self, id: PuzzleId) -> PathBuf {
+        id.year.path("examples").join(&self.file)
+    }
+}
+
+/// The examples of a puzzle, from `<year>/examples/<day>.toml`.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    /// Loads the manifest of the puzzle `id` from the first of `dirs` that has it, or an empty one when none has.
+    ///
+    /// # Errors
+    ///
+    /// When the manifest cannot be read, or is not a valid manifest.
+    pub fn load(dirs: &DataDirs, id: PuzzleId) -> Result<Self, ManifestError> {
+        let path = id.year.path("examples").join(format!("{}.toml", id.day));
+
+        match dirs.read(&path) {
+            Ok(contents) => Self::parse(id, &contents),
+            Err(SolveError::NotFound { .. }) => Ok(Self::default()),
+            Err(error) => Err(ManifestError::Read(error)),
+        }
+    }
+
+    /// Parses the contents of the manifest of the puzzle `id`.
+    ///
+    /// # Errors
+    ///
+    /// When `contents` is not valid TOML, an example holds anything but `file`, `part_1`, `part_2` and `params`, or
+    /// the name of its file does not start with the day.
+    pub fn parse(id: PuzzleId, contents: &str) -> Result<Self, ManifestError> {
+        let raw: RawManifest = toml::from_str(contents).map_err(ManifestError::Toml)?;
+
+        let day = id.day.to_string();
+
+        let examples = raw
+            .examples
+            .into_iter()
+            .map(|example| {
+                let suffix = example
+                    .file
+                    .strip_suffix(".txt")
+                    .and_then(|stem| stem.strip_prefix(&day))
+                    .filter(|suffix| suffix.is_empty() || suffix.starts_with('-'));
+
+                let Some(suffix) = suffix else {
+                    return Err(ManifestError::File(example.file));
+                };
+
+                Ok(Example {
+                    set: format!("examples{}", suffix),
+                    part_1: example.part_1.map(Into::into),
+                    part_2: example.part_2.map(Into::into),
+                    params: example
+                        .params
+                        .into_iter()
+                        .map(|(name, value)| (name, value.to_string()))
+                        .collect(),
+                    file: example.file,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { examples })
+    }
+}
+
+/// An error which can be returned when loading a manifest.
+#[derive(Debug)]
+pub enum ManifestError {
+    Read(SolveError),
+    Toml(TomlError),
+    /// The file of an example is not named after its day, like `08.txt` or `18-2.txt`.
+    File(String),
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ManifestError::Read(ref source) => Some(source),
+            ManifestError::Toml(ref source) => Some(source),
+            ManifestError::File(_) => None,
+        }
+    }
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ManifestError::Read(ref source) => write!(f, "could not load examples: {}", source),
+            ManifestError::Toml(ref source) => write!(f, "invalid manifest: {}", source),
+            ManifestError::File(ref file) => {
+                write!(f, "invalid manifest: `{}` is not named after its day", file)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::PartSolution;
+    use crate::shared::day::PuzzleId;
+    use crate::shared::examples::{Manifest, ManifestError};
+    use crate::shared::runner::Part;
+    use crate::{day, year};
+
+    const MANIFEST: &str = r#"
+[[examples]]
+file = "15-1.txt"
+part_1 = 26
+part_2 = 56_000_011
+params = { row = 10, bound = 20 }
+
+[[examples]]
+file = "15.txt"
+part_2 = "abc"
+"#;
+
+    #[test]
+    fn parse() {
+        let id = PuzzleId::new(year!(2022), day!(15));
+        let manifest = Manifest::parse(id, MANIFEST).unwrap();
+
+        let [ref first, ref second] = manifest.examples[..] else {
+            panic!("expected 2 examples, got {}", manifest.examples.len());
+        };
+
+        assert_eq!("examples-1", first.set);
+        assert_eq!(Some(&PartSolution::I64(26)), first.answer(Part::One));
+        assert_eq!(Some(10_isize), first.param("row"));
+        assert_eq!(None, first.param::<isize>("column"));
+        assert_eq!("2022/examples/15-1.txt", first.path(id).to_str().unwrap());
+
+        assert_eq!("examples", second.set);
+        assert_eq!(None, second.answer(Part::One));
+        assert!(second.params.is_empty(), "parameters are optional");
+    }
+
+    #[test]
+    fn invalid() {
+        let id = PuzzleId::new(year!(2022), day!(15));
+
+        assert!(
+            matches!(
+                Manifest::parse(id, "[[examples]]\nfile = \"150.txt\""),
+                Err(ManifestError::File(_))
+            ),
+            "the file is for day 150"
+        );
+        assert!(
+            matches!(
+                Manifest::parse(id, "[[examples]]\nfile = \"15.txt\"\npart_3 = 1"),
+                Err(ManifestError::Toml(_))
+            ),
+            "there is no part 3"
+        );
+    }
+}
@@ -0,0 +1,674 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write as _};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+use ureq::Agent;
+
+use super::day::PuzzleId;
+use super::error::SolveError;
+use super::input::{Config, DataDirs};
+use super::runner::Part;
+
+/// Where the puzzles live.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie, checked before the `session_file` of the config.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// Identifies us to Advent of Code, as its maintainer asks of automated tools.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_REPOSITORY"),
+    " by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// The least time between two requests.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The value of the `session` cookie of a logged in Advent of Code user.
+pub struct Session(String);
+
+impl Session {
+    #[must_use]
+    pub fn new(token: &str) -> Self {
+        Self(token.trim().to_owned())
+    }
+
+    /// Reads the session cookie from [`SESSION_VARIABLE`], or else from the `session_file` of `config`.
+    ///
+    /// # Errors
+    ///
+    /// When neither is set, or the session file cannot be read.
+    pub fn load(config: &Config) -> Result<Self, FetchError> {
+        if let Some(token) = env::var(SESSION_VARIABLE)
+            .ok()
+            .filter(|token| !token.trim().is_empty())
+        {
+            return Ok(Session::new(&token));
+        }
+
+        let Some(ref path) = config.session_file else {
+            return Err(FetchError::NoSession);
+        };
+
+        let token = fs::read_to_string(path).map_err(|source| {
+            if source.kind() == ErrorKind::NotFound {
+                FetchError::NoSession
+            } else {
+                FetchError::Io {
+                    path: path.clone(),
+                    source,
+                }
+            }
+        })?;
+
+        Ok(Session::new(&token))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.0)
+    }
+}
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // the session is as good as a password
+        f.write_str("Session(<redacted>)")
+    }
+}
+
+/// The status and body of an HTTP response.
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests on behalf of a [`Fetcher`], so tests can stand in for Advent of Code.
+pub trait HttpClient {
+    /// Sends a `GET` request for `url`, authenticated with `session`.
+    ///
+    /// # Errors
+    ///
+    /// When no response was received. Unsuccessful statuses are not errors.
+    fn get(&self, url: &str, session: &Session) -> Result<Response, FetchError>;
+
+    /// Sends a `POST` request to `url` with `form` as its body, authenticated with `session`.
+    ///
+    /// # Errors
+    ///
+    /// When no response was received. Unsuccessful statuses are not errors.
+    fn post(
+        &self,
+        url: &str,
+        session: &Session,
+        form: &[(&str, &str)],
+    ) -> Result<Response, FetchError>;
+}
+
+/// An [`HttpClient`] which sends [`USER_AGENT`] with every request.
+pub struct UreqClient {
+    agent: Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self { agent }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &Session) -> Result<Response, FetchError> {
+        let http = |error: ureq::Error| FetchError::Http(error.to_string());
+
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &session.cookie())
+            .call()
+            .map_err(http)?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string().map_err(http)?,
+        })
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        session: &Session,
+        form: &[(&str, &str)],
+    ) -> Result<Response, FetchError> {
+        let http = |error: ureq::Error| FetchError::Http(error.to_string());
+
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", &session.cookie())
+            .send_form(form.iter().copied())
+            .map_err(http)?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string().map_err(http)?,
+        })
+    }
+}
+
+/// Spaces out requests by at least an interval.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Sleeps until the interval has passed since the previous call.
+    pub fn wait(&mut self) {
+        if let Some(last) = self.last {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+
+        self.last = Some(Instant::now());
+    }
+}
+
+/// What [`Fetcher::fetch_input`] did.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was there already, and was not downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads puzzle inputs into the data directory, where [`read_file`](super::solution::read_file) finds them, and
+/// submits answers.
+pub struct Fetcher<C: HttpClient> {
+    client: C,
+    session: Session,
+    base_url: String,
+    limiter: RateLimiter,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    #[must_use]
+    pub fn new(client: C, session: Session, base_url: &str, interval: Duration) -> Self {
+        Self {
+            client,
+            session,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            limiter: RateLimiter::new(interval),
+        }
+    }
+
+    /// Downloads the input of the puzzle `id` into the first of `dirs` that exists, unless one of `dirs` already has it.
+    ///
+    /// # Errors
+    ///
+    /// When there is no data directory, the download fails, or the input cannot be written.
+    pub fn fetch_input(&mut self, id: PuzzleId, dirs: &DataDirs) -> Result<Fetched, FetchError> {
+        let relative = id.path("inputs");
+
+        if let Ok(path) = dirs.resolve(&relative) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let path = dirs
+            .first_existing()
+            .map_err(FetchError::Data)?
+            .join(relative);
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url,
+            id.year,
+            id.day.into_inner()
+        );
+
+        self.limiter.wait();
+
+        let response = self.client.get(&url, &self.session)?;
+
+        match response.status {
+            200 => {},
+            404 => return Err(FetchError::NotAvailable(id)),
+            400 | 401 | 403 => return Err(FetchError::SessionRejected),
+            status => return Err(FetchError::Status { url, status }),
+        }
+
+        write_new(&path, &response.body)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits `answer` to `part` of the puzzle `id`, returning the page Advent of Code replied with.
+    ///
+    /// # Errors
+    ///
+    /// When the request fails or is not successful.
+    pub fn post_answer(
+        &mut self,
+        id: PuzzleId,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, FetchError> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url,
+            id.year,
+            id.day.into_inner()
+        );
+
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+
+        self.limiter.wait();
+
+        let response =
+            self.client
+                .post(&url, &self.session, &[("level", level), ("answer", answer)])?;
+
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(FetchError::NotAvailable(id)),
+            400 | 401 | 403 => Err(FetchError::SessionRejected),
+            status => Err(FetchError::Status { url, status }),
+        }
+    }
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let io = |source| FetchError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(io)
+}
+
+/// Why an input could not be fetched.
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither [`SESSION_VARIABLE`] is set, nor does the session file exist.
+    NoSession,
+    /// Advent of Code did not accept the session, which has likely expired.
+    SessionRejected,
+    /// The puzzle is not unlocked yet.
+    NotAvailable(PuzzleId),
+    /// Any other unsuccessful response.
+    Status { url: String, status: u16 },
+    /// No response was received.
+    Http(String),
+    /// The config or the data directories are unusable.
+    Data(SolveError),
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            FetchError::Data(ref source) => Some(source),
+            FetchError::Io { ref source, .. } => Some(source),
+            FetchError::NoSession
+            | FetchError::SessionRejected
+            | FetchError::NotAvailable(_)
+            | FetchError::Status { .. }
+            | FetchError::Http(_) => None,
+        }
+    }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            FetchError::NoSession => write!(
+                f,
+                "no session cookie, set `{}` or write it to the `session_file` of `.aoc.toml`",
+                SESSION_VARIABLE
+            ),
+            FetchError::SessionRejected => {
+                f.write_str("the session cookie was rejected, it has likely expired")
+            },
+            FetchError::NotAvailable(id) => write!(f, "{} is not unlocked yet", id),
+            FetchError::Status { ref url, status } => {
+                write!(f, "unexpected status {} from {}", status, url)
+            },
+            FetchError::Http(ref message) => write!(f, "request failed: {}", message),
+            FetchError::Data(ref source) => Display::fmt(source, f),
+            FetchError::Io {
+                ref path,
+                ref source,
+            } => write!(f, "could not access \"{}\": {}", path.display(), source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+    use std::{fs, thread};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::day::PuzzleId;
+    use crate::shared::fetch::{
+        FetchError, Fetched, Fetcher, HttpClient, RateLimiter, Response, Session, USER_AGENT,
+        UreqClient,
+    };
+    use crate::shared::input::DataDirs;
+    use crate::shared::runner::Part;
+    use crate::shared::testing::temp_path;
+    use crate::{day, year};
+
+    /// Answers every request with the same status and body, remembering the urls and forms.
+    struct StandIn {
+        status: u16,
+        urls: RefCell<Vec<String>>,
+        forms: RefCell<Vec<Vec<(String, String)>>>,
+    }
+
+    impl StandIn {
+        fn new(status: u16) -> Self {
+            Self {
+                status,
+                urls: RefCell::new(Vec::new()),
+                forms: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for &StandIn {
+        fn get(&self, url: &str, _session: &Session) -> Result<Response, FetchError> {
+            self.urls.borrow_mut().push(url.to_owned());
+
+            Ok(Response {
+                status: self.status,
+                body: "1\n2\n3\n".to_owned(),
+            })
+        }
+
+        fn post(
+            &self,
+            url: &str,
+            session: &Session,
+            form: &[(&str, &str)],
+        ) -> Result<Response, FetchError> {
+            self.forms.borrow_mut().push(
+                form.iter()
+                    .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+                    .collect(),
+            );
+
+            self.get(url, session)
+        }
+    }
+
+    fn data_dir(name: &str) -> PathBuf {
+        let dir = temp_path(&format!("fetch-{}", name));
+
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = data_dir("downloads-once");
+        let dirs = DataDirs::single(dir.clone());
+        let stand_in = StandIn::new(200);
+
+        let mut fetcher = Fetcher::new(
+            &stand_in,
+            Session::new("secret\n"),
+            "http://localhost/",
+            Duration::ZERO,
+        );
+
+        let path = dir.join("2022").join("inputs").join("07.txt");
+
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetcher.fetch_input(day!(7).into(), &dirs).unwrap()
+        );
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetcher.fetch_input(day!(7).into(), &dirs).unwrap()
+        );
+        assert_eq!(
+            vec!["http://localhost/2022/day/7/input".to_owned()],
+            *stand_in.urls.borrow()
+        );
+        assert_eq!("1\n2\n3\n", fs::read_to_string(path).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn not_available() {
+        let dir = data_dir("not-available");
+        let dirs = DataDirs::single(dir.clone());
+        let stand_in = StandIn::new(404);
+
+        let mut fetcher = Fetcher::new(
+            &stand_in,
+            Session::new("secret"),
+            "http://localhost",
+            Duration::ZERO,
+        );
+
+        assert!(
+            matches!(
+                fetcher.fetch_input(day!(25).into(), &dirs),
+                Err(FetchError::NotAvailable(_))
+            ),
+            "a 404 means the day is locked"
+        );
+        assert!(
+            !dir.join("2022").join("inputs").join("25.txt").exists(),
+            "nothing is written on failure"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn posts_answer() {
+        let stand_in = StandIn::new(200);
+
+        let mut fetcher = Fetcher::new(
+            &stand_in,
+            Session::new("secret"),
+            "http://localhost",
+            Duration::ZERO,
+        );
+
+        assert_eq!(
+            "1\n2\n3\n",
+            fetcher
+                .post_answer(PuzzleId::new(year!(2021), day!(3)), Part::Two, "157")
+                .unwrap()
+        );
+        assert_eq!(
+            vec!["http://localhost/2021/day/3/answer".to_owned()],
+            *stand_in.urls.borrow()
+        );
+        assert_eq!(
+            vec![vec![
+                ("level".to_owned(), "2".to_owned()),
+                ("answer".to_owned(), "157".to_owned())
+            ]],
+            *stand_in.forms.borrow()
+        );
+    }
+
+    #[test]
+    fn rate_limited() {
+        let mut limiter = RateLimiter::new(Duration::from_millis(50));
+
+        let start = Instant::now();
+
+        limiter.wait();
+        limiter.wait();
+
+        assert!(
+            start.elapsed() >= Duration::from_millis(50),
+            "the second request waits for the interval"
+        );
+    }
+
+    #[test]
+    fn session_is_redacted() {
+        assert_eq!(
+            "Session(<redacted>)",
+            format!("{:?}", Session::new("secret"))
+        );
+    }
+
+    #[test]
+    fn ureq_against_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut headers = Vec::new();
+
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                headers.push(line.to_lowercase());
+            }
+
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\n1\n2\n",
+                )
+                .unwrap();
+
+            headers
+        });
+
+        let response = UreqClient::default()
+            .get(
+                &format!("http://{}/2022/day/1/input", address),
+                &Session::new("secret"),
+            )
+            .unwrap();
+
+        let headers = server.join().unwrap();
+
+        assert_eq!(200, response.status);
+        assert_eq!("1\n2\n", response.body);
+        assert!(
+            headers.contains(&"cookie: session=secret".to_owned()),
+            "the session is sent as a cookie"
+        );
+        assert!(
+            headers.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())),
+            "the user agent identifies us"
+        );
+    }
+
+    #[test]
+    fn ureq_posts_form() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                let line = line.trim_end().to_lowercase();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some(value) = line.strip_prefix("content-length: ") {
+                    length = value.parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .unwrap();
+
+            (request_line, String::from_utf8(body).unwrap())
+        });
+
+        let response = UreqClient::default()
+            .post(
+                &format!("http://{}/2022/day/1/answer", address),
+                &Session::new("secret"),
+                &[("level", "1"), ("answer", "a b")],
+            )
+            .unwrap();
+
+        let (request_line, body) = server.join().unwrap();
+
+        assert_eq!("ok", response.body);
+        assert!(
+            request_line.starts_with("POST /2022/day/1/answer "),
+            "the answer is posted"
+        );
+        assert_eq!("level=1&answer=a+b", body);
+    }
+}
@@ -0,0 +1,303 @@
+use std::convert::Infallible;
+use std::ffi::OsString;
+use std::io::{Read as _, stdin};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+use serde::Deserialize;
+
+use super::day::PuzzleId;
+use super::error::SolveError;
+
+/// The environment variable naming a data directory, searched before any other.
+pub const DATA_DIR_VARIABLE: &str = "AOC_DATA_DIR";
+
+/// The config file, looked up in the current directory and then in each of its ancestors.
+pub const CONFIG_FILE: &str = ".aoc.toml";
+
+/// Where a day's input is read from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// A single file, regardless of the day.
+    Path(PathBuf),
+    /// Standard input, written as `-`.
+    Stdin,
+    /// `<year>/<folder>/<day>.txt` in the first of the [`DataDirs`] that has it.
+    #[default]
+    DataDirs,
+}
+
+impl InputSource {
+    /// Reads the input of the puzzle `id` from this source, with `folder` being `inputs`, `examples`, ...
+    ///
+    /// # Errors
+    ///
+    /// [`SolveError::NotFound`] when no data directory has the file, or [`SolveError::Io`] when it cannot be read.
+    pub fn read(&self, folder: &str, id: PuzzleId) -> Result<String, SolveError> {
+        match *self {
+            InputSource::Path(ref path) => read(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| SolveError::Io {
+                        path: PathBuf::from("-"),
+                        source,
+                    })?;
+
+                Ok(input)
+            },
+            InputSource::DataDirs => DataDirs::discover()?.read(&id.path(folder)),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            Ok(InputSource::Stdin)
+        } else {
+            Ok(InputSource::Path(PathBuf::from(s)))
+        }
+    }
+}
+
+/// The settings of the nearest [`CONFIG_FILE`], with its paths relative to the directory holding it.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// A data directory, searched after [`DATA_DIR_VARIABLE`].
+    pub data_dir: Option<PathBuf>,
+    /// The file holding the session cookie to download inputs with.
+    pub session_file: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads the nearest [`CONFIG_FILE`], looking in `cwd` and then in each of its ancestors.
+    ///
+    /// Returns the default, empty, config when there is none.
+    ///
+    /// # Errors
+    ///
+    /// When the config file cannot be read or is invalid.
+    pub fn load(cwd: &Path) -> Result<Self, SolveError> {
+        let Some(path) = cwd
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+        else {
+            return Ok(Config::default());
+        };
+
+        Config::parse(&path, &read(&path)?)
+    }
+
+    fn parse(path: &Path, contents: &str) -> Result<Self, SolveError> {
+        let config: Config = toml::from_str(contents).map_err(|error| SolveError::Config {
+            path: path.to_path_buf(),
+            message: error.message().to_owned(),
+        })?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+
+        Ok(Config {
+            data_dir: config.data_dir.map(|data_dir| dir.join(data_dir)),
+            session_file: config
+                .session_file
+                .map(|session_file| dir.join(session_file)),
+        })
+    }
+}
+
+/// The directories holding a folder per year with `inputs/`, `examples/` and `answers.toml`, in the order they are
+/// searched:
+/// [`DATA_DIR_VARIABLE`], the `data_dir` of the nearest [`CONFIG_FILE`], then `data/` in the current directory.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataDirs {
+    dirs: Vec<PathBuf>,
+}
+
+impl DataDirs {
+    /// Finds the data directories from the environment, the config file and the current directory.
+    ///
+    /// # Errors
+    ///
+    /// When the current directory is inaccessible, or the config file cannot be read or is invalid.
+    pub fn discover() -> Result<Self, SolveError> {
+        let cwd = env::current_dir().map_err(|source| SolveError::Io {
+            path: PathBuf::from("."),
+            source,
+        })?;
+
+        let config = Config::load(&cwd)?;
+
+        Ok(DataDirs::new(
+            env::var_os(DATA_DIR_VARIABLE),
+            config.data_dir,
+            &cwd,
+        ))
+    }
+
+    /// Only `dir`, regardless of the environment and the config file.
+    #[must_use]
+    pub fn single(dir: PathBuf) -> Self {
+        Self { dirs: vec![dir] }
+    }
+
+    /// Every data directory, in the order they are searched, whether it exists or not.
+    #[must_use]
+    pub fn dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    fn new(variable: Option<OsString>, config: Option<PathBuf>, cwd: &Path) -> Self {
+        let mut dirs = Vec::new();
+
+        for dir in variable
+            .map(|variable| cwd.join(variable))
+            .into_iter()
+            .chain(config)
+            .chain([cwd.join("data")])
+        {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+
+        Self { dirs }
+    }
+
+    /// The path of `relative` in the first data directory that has it.
+    ///
+    /// # Errors
+    ///
+    /// [`SolveError::NotFound`], listing every path that was tried, when no data directory has it.
+    pub fn resolve(&self, relative: &Path) -> Result<PathBuf, SolveError> {
+        let tried = self
+            .dirs
+            .iter()
+            .map(|dir| dir.join(relative))
+            .collect::<Vec<_>>();
+
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(SolveError::NotFound { tried }),
+        }
+    }
+
+    /// The first data directory that exists, which is where new files go.
+    ///
+    /// # Errors
+    ///
+    /// [`SolveError::NotFound`], listing every directory that was tried, when none of them exists.
+    pub fn first_existing(&self) -> Result<&Path, SolveError> {
+        match self.dirs.iter().find(|dir| dir.is_dir()) {
+            Some(dir) => Ok(dir),
+            None => Err(SolveError::NotFound {
+                tried: self.dirs.clone(),
+            }),
+        }
+    }
+
+    /// Reads `relative` from the first data directory that has it.
+    ///
+    /// # Errors
+    ///
+    /// When no data directory has it, or it cannot be read.
+    pub fn read(&self, relative: &Path) -> Result<String, SolveError> {
+        read(&self.resolve(relative)?)
+    }
+}
+
+fn read(path: &Path) -> Result<String, SolveError> {
+    fs::read_to_string(path).map_err(|source| SolveError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::error::SolveError;
+    use crate::shared::input::{Config, DataDirs, InputSource};
+
+    #[test]
+    fn input_source() {
+        assert_eq!(Ok(InputSource::Stdin), "-".parse());
+        assert_eq!(
+            Ok(InputSource::Path(PathBuf::from("input.txt"))),
+            "input.txt".parse()
+        );
+    }
+
+    #[test]
+    fn search_order() {
+        let dirs = DataDirs::new(
+            Some(OsString::from("/srv/aoc")),
+            Some(PathBuf::from("/work/data")),
+            Path::new("/work"),
+        );
+
+        assert_eq!(
+            vec![PathBuf::from("/srv/aoc"), PathBuf::from("/work/data")],
+            dirs.dirs
+        );
+
+        let dirs = DataDirs::new(Some(OsString::from("custom")), None, Path::new("/work"));
+
+        assert_eq!(
+            vec![PathBuf::from("/work/custom"), PathBuf::from("/work/data")],
+            dirs.dirs
+        );
+    }
+
+    #[test]
+    fn not_found() {
+        let dirs = DataDirs::new(
+            Some(OsString::from("/nonexistent/1")),
+            Some(PathBuf::from("/nonexistent/2")),
+            Path::new("/nonexistent/3"),
+        );
+
+        let error = dirs.resolve(Path::new("inputs/07.txt")).unwrap_err();
+
+        assert_eq!(
+            "not found, tried \"/nonexistent/1/inputs/07.txt\", \"/nonexistent/2/inputs/07.txt\", \"/nonexistent/3/data/inputs/07.txt\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn config() {
+        let path = Path::new("/work/.aoc.toml");
+
+        assert_eq!(
+            Config {
+                data_dir: Some(PathBuf::from("/work/crates/advent-of-code-2022/data")),
+                session_file: Some(PathBuf::from("/work/.aoc-session")),
+            },
+            Config::parse(
+                path,
+                "data_dir = \"crates/advent-of-code-2022/data\"\nsession_file = \".aoc-session\""
+            )
+            .unwrap()
+        );
+        assert_eq!(Config::default(), Config::parse(path, "").unwrap());
+        assert!(
+            matches!(
+                Config::parse(path, "data = \"data\""),
+                Err(SolveError::Config { .. })
+            ),
+            "`data` is not a setting"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt::Display;
+
+/// The heap usage of a piece of code, as counted by the allocator of the `count-allocations` feature.
+///
+/// Only the heap is counted, not the resident memory of the process, and only what the current thread allocates: what a
+/// part allocates on threads of its own is missed, as is what parts solved alongside it, like under `--jobs`, allocate.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of allocations, including reallocations.
+    pub allocations: usize,
+    /// The bytes asked for by all of those allocations.
+    pub bytes: usize,
+    /// The peak heap: the most bytes that were allocated at once, on top of what was allocated before.
+    pub peak_heap: usize,
+}
+
+impl Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak heap",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak_heap)
+        )
+    }
+}
+
+/// A number of bytes, displayed in the largest binary unit that keeps it above 1.
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut value = self.0;
+        let mut remainder = 0;
+        let mut unit = 0;
+
+        while value >= 1024 && unit + 1 < UNITS.len() {
+            remainder = value % 1024;
+            value /= 1024;
+            unit += 1;
+        }
+
+        let name = UNITS.get(unit).copied().unwrap_or_default();
+
+        if unit == 0 {
+            write!(f, "{} {}", value, name)
+        } else {
+            write!(f, "{}.{} {}", value, remainder * 10 / 1024, name)
+        }
+    }
+}
+
+/// Runs `f`, counting the allocations it makes on the current thread, but not those on other threads.
+///
+/// Returns [`None`] for the usage when the `count-allocations` feature is disabled.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<MemoryUsage>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let start = counting::snapshot();
+        let result = f();
+        let end = counting::snapshot();
+
+        (result, Some(end.since(&start)))
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::MemoryUsage;
+
+    /// Wraps the [`System`] allocator, counting what each thread allocates.
+    ///
+    /// Counting per thread keeps the numbers of parts solved in parallel apart.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+        static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+        static CURRENT: Cell<usize> = const { Cell::new(0) };
+        static PEAK: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn on_alloc(size: usize) {
+        // `try_with`, as allocations happen while threads are torn down, too
+        let current = CURRENT
+            .try_with(|current| {
+                current.set(current.get().saturating_add(size));
+                current.get()
+            })
+            .unwrap_or_default();
+
+        ALLOCATIONS
+            .try_with(|allocations| allocations.set(allocations.get().saturating_add(1)))
+            .unwrap_or_default();
+        ALLOCATED
+            .try_with(|allocated| allocated.set(allocated.get().saturating_add(size)))
+            .unwrap_or_default();
+        PEAK.try_with(|peak| peak.set(peak.get().max(current)))
+            .unwrap_or_default();
+    }
+
+    fn on_dealloc(size: usize) {
+        CURRENT
+            .try_with(|current| current.set(current.get().saturating_sub(size)))
+            .unwrap_or_default();
+    }
+
+    // SAFETY: every call is forwarded to `System` unchanged, the counting does not allocate
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            on_alloc(layout.size());
+
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc`
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            on_alloc(layout.size());
+
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::alloc_zeroed`
+            unsafe { System.alloc_zeroed(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            on_dealloc(layout.size());
+
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::dealloc`
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            on_dealloc(layout.size());
+            on_alloc(new_size);
+
+            // SAFETY: the caller upholds the contract of `GlobalAlloc::realloc`
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    /// The counters of the current thread at some point.
+    pub(super) struct Snapshot {
+        allocations: usize,
+        allocated: usize,
+        current: usize,
+        /// The peak since the previous snapshot.
+        peak: usize,
+    }
+
+    impl Snapshot {
+        /// The usage between `start` and this snapshot.
+        pub(super) fn since(&self, start: &Snapshot) -> MemoryUsage {
+            MemoryUsage {
+                allocations: self.allocations.saturating_sub(start.allocations),
+                bytes: self.allocated.saturating_sub(start.allocated),
+                peak_heap: self.peak.saturating_sub(start.current),
+            }
+        }
+    }
+
+    /// Takes a snapshot of the counters, and starts tracking the peak from here.
+    pub(super) fn snapshot() -> Snapshot {
+        let current = CURRENT.with(Cell::get);
+
+        Snapshot {
+            allocations: ALLOCATIONS.with(Cell::get),
+            allocated: ALLOCATED.with(Cell::get),
+            current,
+            peak: PEAK.with(|peak| peak.replace(current)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::memory::{MemoryUsage, measure};
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "3 allocations, 512 B allocated, 1.5 MiB peak heap",
+            MemoryUsage {
+                allocations: 3,
+                bytes: 512,
+                peak_heap: 1536 * 1024,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn measures() {
+        let (length, usage) = measure(|| vec![0_u8; 4096].len());
+
+        assert_eq!(4096, length);
+
+        if cfg!(feature = "count-allocations") {
+            let usage = usage.unwrap();
+
+            assert!(usage.allocations >= 1, "the vector was allocated");
+            assert!(usage.bytes >= 4096, "the vector holds 4096 bytes");
+            assert!(usage.peak_heap >= 4096, "the vector was alive at once");
+        } else {
+            assert_eq!(None, usage);
+        }
+    }
+}
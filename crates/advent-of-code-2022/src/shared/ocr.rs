@@ -0,0 +1,197 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// How many pixels high a letter is.
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// How many pixels wide a letter is. Letters are one column apart.
+pub const GLYPH_WIDTH: usize = 4;
+
+/// The pixel which is lit; every other one is dark.
+const LIT: char = '#';
+
+/// The letters of the block font puzzles draw their answers in, top row first.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters spelled by `rows` of `#` (lit) and `.` (dark) pixels, as drawn by a CRT or folded paper.
+///
+/// # Errors
+///
+/// When there are not [`GLYPH_HEIGHT`] rows, or a letter is not in the font.
+pub fn recognize<S: AsRef<str>>(rows: &[S]) -> Result<String, OcrError> {
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+
+    let rows = rows
+        .iter()
+        .map(|row| row.as_ref().chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..width)
+        .step_by(GLYPH_WIDTH + 1)
+        .enumerate()
+        .map(|(index, column)| {
+            // normalized, so that a row cut short reads as dark
+            let glyph = rows
+                .iter()
+                .map(|row| {
+                    (column..column + GLYPH_WIDTH)
+                        .map(|column| {
+                            if row.get(column) == Some(&LIT) {
+                                LIT
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+
+            FONT.iter()
+                .find(|&&(_, ref pixels)| glyph == pixels)
+                .map(|&(letter, _)| letter)
+                .ok_or(OcrError::UnknownGlyph {
+                    index,
+                    column,
+                    glyph,
+                })
+        })
+        .collect()
+}
+
+/// An error which can be returned when reading letters with [`recognize`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The letters are not [`GLYPH_HEIGHT`] rows high, but this many.
+    Height(usize),
+    /// The letter `index` (0-based), starting at `column` (0-based), is not in the font.
+    UnknownGlyph {
+        index: usize,
+        column: usize,
+        glyph: Vec<String>,
+    },
+}
+
+impl Error for OcrError {}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            OcrError::Height(height) => {
+                write!(f, "expected {} rows, found {}", GLYPH_HEIGHT, height)
+            },
+            OcrError::UnknownGlyph {
+                index,
+                column,
+                ref glyph,
+            } => write!(
+                f,
+                "unknown letter {} at column {}: {}",
+                index + 1,
+                column + 1,
+                glyph.join("/")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::ocr::{FONT, GLYPH_WIDTH, OcrError, recognize};
+
+    #[test]
+    fn reads_letters() {
+        let rows = [
+            "####..##...##....##.####...##.####.#....",
+            "...#.#..#.#..#....#....#....#.#....#....",
+            "..#..#....#.......#...#.....#.###..#....",
+            ".#...#.##.#.......#..#......#.#....#....",
+            "#....#..#.#..#.#..#.#....#..#.#....#....",
+            "####..###..##...##..####..##..#....####.",
+        ];
+
+        assert_eq!(Ok("ZGCJZJFL".to_owned()), recognize(&rows));
+    }
+
+    #[test]
+    fn reads_the_whole_font() {
+        let rows = (0..6)
+            .map(|row| {
+                FONT.iter()
+                    .map(|&(_, ref pixels)| pixels[row])
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect::<Vec<_>>();
+
+        let letters = FONT.iter().map(|&(letter, _)| letter).collect::<String>();
+
+        assert_eq!(Ok(letters), recognize(&rows));
+        assert!(
+            FONT.iter()
+                .all(|&(_, ref pixels)| pixels.iter().all(|row| row.len() == GLYPH_WIDTH)),
+            "every letter is 4 pixels wide"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let rows = [
+            "####.##..",
+            "#....##..",
+            "###..##..",
+            "#....##..",
+            "#....##..",
+            "####.##..",
+        ];
+
+        let error = recognize(&rows).unwrap_err();
+
+        assert_eq!(
+            OcrError::UnknownGlyph {
+                index: 1,
+                column: 5,
+                glyph: vec![
+                    "##..".to_owned(),
+                    "##..".to_owned(),
+                    "##..".to_owned(),
+                    "##..".to_owned(),
+                    "##..".to_owned(),
+                    "##..".to_owned(),
+                ],
+            },
+            error
+        );
+        assert_eq!(
+            "unknown letter 2 at column 6: ##../##../##../##../##../##..",
+            error.to_string()
+        );
+
+        assert_eq!(Err(OcrError::Height(2)), recognize(&["#", "#"]));
+        assert_eq!("expected 6 rows, found 2", OcrError::Height(2).to_string());
+    }
+}
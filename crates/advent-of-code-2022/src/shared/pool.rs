@@ -0,0 +1,302 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{OnceLock, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::context::SolveContext;
+use super::day::{PuzzleId, Year};
+use super::error::SolveError;
+use super::input::InputSource;
+use super::runner::{DayOutcome, DaySelection, ParsedInput, Part, PartOutcome, Registry, Solver};
+
+/// A day whose input has been read, waiting for its parts to be solved.
+struct Pending<'a> {
+    id: PuzzleId,
+    solver: &'a dyn Solver,
+    input: Result<String, SolveError>,
+    read: Duration,
+    /// The input as parsed by whichever part is solved first, and how long that took, for the other parts to share.
+    parsed: OnceLock<(Result<ParsedInput, SolveError>, Duration)>,
+}
+
+/// Like [`run`](super::runner::run), but solves every requested part of every day on one of `jobs` worker threads, with
+/// the default [`SolveContext`].
+///
+/// Inputs are read up front, so that stdin is read once. Each input is parsed once, by the first of its parts to be
+/// picked up, which the other parts wait for. A part that panics is reported as [`SolveError::Panicked`] without
+/// affecting the others. The outcomes are written to `out` in
+/// day order once every part is done.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn run_parallel(
+    registry: &Registry,
+    year: Year,
+    selection: DaySelection,
+    parts: &[Part],
+    source: &InputSource,
+    jobs: NonZeroUsize,
+    out: &mut dyn Write,
+) -> std::io::Result<Vec<DayOutcome>> {
+    let pending = registry
+        .iter()
+        .filter(|&(&id, _)| id.year == year && selection.contains(id.day))
+        .map(|(&id, solver)| {
+            let start = Instant::now();
+            let input = source.read("inputs", id);
+
+            Pending {
+                id,
+                solver: solver.as_ref(),
+                input,
+                read: start.elapsed(),
+                parsed: OnceLock::new(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let tasks = pending
+        .iter()
+        .enumerate()
+        .filter(|&(_, day)| day.input.is_ok())
+        .flat_map(|(index, _)| parts.iter().map(move |&part| (index, part)))
+        .collect::<Vec<_>>();
+
+    let mut solved = BTreeMap::new();
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(tasks.len()) {
+            let sender = sender.clone();
+            let (pending, tasks, next) = (&pending, &tasks, &next);
+
+            scope.spawn(move || {
+                while let Some(&(index, part)) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = pending.get(index).and_then(|day| solve_part(day, part));
+
+                    // the receiver outlives the workers, so sending never fails
+                    if sender.send(((index, part), outcome)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(sender);
+
+        solved.extend(receiver);
+    });
+
+    let outcomes = pending
+        .into_iter()
+        .enumerate()
+        .map(|(index, day)| {
+            let solved = parts
+                .iter()
+                .filter_map(|&part| solved.remove(&(index, part)).flatten())
+                .collect();
+
+            merge(day, solved)
+        })
+        .collect::<Vec<_>>();
+
+    for outcome in &outcomes {
+        outcome.write_solutions(out)?;
+    }
+
+    Ok(outcomes)
+}
+
+/// Solves a single `part` of `day`, parsing its input first unless another part did, and turning a panic into an error.
+///
+/// Returns [`None`] when the input cannot be parsed, which [`merge`] reports for the day as a whole.
+fn solve_part(day: &Pending<'_>, part: Part) -> Option<PartOutcome> {
+    let input = day.input.as_deref().unwrap_or_default();
+
+    let start = Instant::now();
+
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let &(ref parsed, _) = day.parsed.get_or_init(|| {
+            let start = Instant::now();
+            let parsed = day.solver.parse(input);
+
+            (parsed, start.elapsed())
+        });
+
+        parsed.as_ref().ok().map(|parsed| {
+            day.solver
+                .solve_parsed(day.id.day, parsed, part, &SolveContext::default())
+        })
+    }))
+    .unwrap_or_else(|payload| {
+        Some(PartOutcome {
+            part,
+            solution: Err(SolveError::Panicked(panic_message(payload.as_ref()))),
+            solve: start.elapsed(),
+            memory: None,
+        })
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|&message| message.to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown payload".to_owned())
+}
+
+/// Combines the `solved` parts of `day` into its outcome, as if they were solved together.
+fn merge(day: Pending<'_>, solved: Vec<PartOutcome>) -> DayOutcome {
+    if let Err(error) = day.input {
+        return DayOutcome {
+            day: day.id.day,
+            parse: day.read,
+            parts: Err(error),
+        };
+    }
+
+    // nothing is parsed when no part is asked for, or parsing panicked
+    let (parsed, parse) = day
+        .parsed
+        .into_inner()
+        .map_or((Ok(()), Duration::ZERO), |(parsed, parse)| {
+            (parsed.map(drop), parse)
+        });
+
+    DayOutcome {
+        day: day.id.day,
+        parse: day.read + parse,
+        parts: parsed.map(|()| solved),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::num::NonZeroUsize;
+    use std::sync::OnceLock;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::day::PuzzleId;
+    use crate::shared::error::SolveError;
+    use crate::shared::input::InputSource;
+    use crate::shared::pool::{Pending, merge, run_parallel, solve_part};
+    use crate::shared::runner::{DaySelection, Part, Registry};
+    use crate::shared::testing::{Lines, temp_path};
+    use crate::{day, year};
+
+    #[test]
+    fn collects_in_day_order() {
+        let path = temp_path("pool.txt");
+        fs::write(&path, "a\nb\nc\n").unwrap();
+
+        let mut registry = Registry::new();
+
+        for day in [day!(3), day!(1), day!(2)] {
+            registry.insert(
+                PuzzleId::new(year!(2022), day),
+                Box::new(Lines {
+                    panics: day == day!(2),
+                    ..Lines::default()
+                }),
+            );
+        }
+
+        let mut out = Vec::new();
+
+        let outcomes = run_parallel(
+            &registry,
+            year!(2022),
+            DaySelection::All,
+            &Part::ALL,
+            &InputSource::Path(path.clone()),
+            NonZeroUsize::new(4).unwrap(),
+            &mut out,
+        )
+        .unwrap();
+
+        fs::remove_file(path).unwrap();
+
+        let solutions = outcomes
+            .iter()
+            .map(|outcome| {
+                let parts = outcome.parts.as_ref().unwrap();
+
+                (
+                    outcome.day,
+                    parts
+                        .iter()
+                        .map(|part| part.solution.as_ref().ok().map(ToString::to_string))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (day!(1), vec![Some("3".to_owned()), Some("3".to_owned())]),
+                (day!(2), vec![Some("3".to_owned()), None]),
+                (day!(3), vec![Some("3".to_owned()), Some("3".to_owned())]),
+            ],
+            solutions
+        );
+        assert!(
+            matches!(
+                outcomes[1].errors().next(),
+                Some((Some(Part::Two), &SolveError::Panicked(ref message))) if message == "part 2 panics"
+            ),
+            "the panic is reported as an error of its part"
+        );
+        assert!(
+            String::from_utf8(out).unwrap().starts_with("Day 01 "),
+            "the outcomes are written in day order"
+        );
+    }
+
+    #[test]
+    fn parses_once_per_day() {
+        let lines = Lines::default();
+
+        let day = Pending {
+            id: PuzzleId::new(year!(2022), day!(1)),
+            solver: &lines,
+            input: Ok("a\nbc\n".to_owned()),
+            read: Duration::ZERO,
+            parsed: OnceLock::new(),
+        };
+
+        let solved = Part::ALL
+            .into_iter()
+            .filter_map(|part| solve_part(&day, part))
+            .collect();
+
+        let outcome = merge(day, solved);
+
+        assert_eq!(
+            1,
+            lines.parsed.load(Ordering::Relaxed),
+            "both parts share the input"
+        );
+        assert_eq!(
+            vec!["2".to_owned(), "3".to_owned()],
+            outcome
+                .parts
+                .unwrap()
+                .iter()
+                .map(|part| part.solution.as_ref().unwrap().to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::PartSolution;
+use super::answers::{Answers, Verdict};
+use super::day::Year;
+use super::runner::DayOutcome;
+
+/// How the outcomes of a run are reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// The solutions as they come, then the summary table.
+    #[default]
+    Text,
+    /// A single JSON document, for scripts.
+    Json,
+    /// `JUnit` XML, with a test suite per day and a test case per part, for CI dashboards.
+    Junit,
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "text" => Ok(ReportFormat::Text),
+            "json" => Ok(ReportFormat::Json),
+            "junit" => Ok(ReportFormat::Junit),
+            _ => Err(ReportFormatFromStrError {}),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`ReportFormat`].
+#[derive(Debug)]
+pub struct ReportFormatFromStrError {}
+
+impl Error for ReportFormatFromStrError {}
+
+impl Display for ReportFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `text`, `json` or `junit`")
+    }
+}
+
+/// How a part fared against its known answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Passed,
+    Failed,
+    /// There is no known answer to compare with.
+    Unknown,
+    /// The part could not be solved.
+    Error,
+}
+
+/// A part of a day, or the day itself when its input could not be prepared.
+#[derive(Debug, Serialize)]
+struct PartReport<'a> {
+    day: u8,
+    /// Missing when the input could not be read or parsed, which fails every part.
+    part: Option<u8>,
+    status: Status,
+    answer: Option<&'a PartSolution>,
+    expected: Option<&'a PartSolution>,
+    parse_seconds: f64,
+    solve_seconds: f64,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    year: u16,
+    parts: Vec<PartReport<'a>>,
+}
+
+fn part_reports<'a>(outcomes: &'a [DayOutcome], answers: &'a Answers) -> Vec<PartReport<'a>> {
+    let mut reports = Vec::new();
+
+    for outcome in outcomes {
+        let day = outcome.day.into_inner();
+
+        let parts = match outcome.parts {
+            Ok(ref parts) => parts,
+            Err(ref error) => {
+                reports.push(PartReport {
+                    day,
+                    part: None,
+                    status: Status::Error,
+                    answer: None,
+                    expected: None,
+                    parse_seconds: outcome.parse.as_secs_f64(),
+                    solve_seconds: 0.0,
+                    error: Some(error.to_string()),
+                });
+
+                continue;
+            },
+        };
+
+        for part in parts {
+            let expected = answers.get(outcome.day, "inputs", part.part);
+
+            let (status, answer, error) = match part.solution {
+                Ok(ref solution) => {
+                    let status = match answers.verdict(outcome.day, "inputs", part.part, solution) {
+                        Verdict::Correct => Status::Passed,
+                        Verdict::Wrong { .. } => Status::Failed,
+                        Verdict::Unknown => Status::Unknown,
+                    };
+
+                    (status, Some(solution), None)
+                },
+                Err(ref error) => (Status::Error, None, Some(error.to_string())),
+            };
+
+            reports.push(PartReport {
+                day,
+                part: Some(part.part.into()),
+                status,
+                answer,
+                expected,
+                parse_seconds: outcome.parse.as_secs_f64(),
+                solve_seconds: part.solve.as_secs_f64(),
+                error,
+            });
+        }
+    }
+
+    reports
+}
+
+/// Whether any part of `outcomes` failed or could not be solved.
+#[must_use]
+pub fn has_failures(outcomes: &[DayOutcome], answers: &Answers) -> bool {
+    part_reports(outcomes, answers)
+        .iter()
+        .any(|report| matches!(report.status, Status::Failed | Status::Error))
+}
+
+/// Writes `outcomes` of `year` as a JSON document, comparing each answer with the known one in `answers`.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn write_json(
+    year: Year,
+    outcomes: &[DayOutcome],
+    answers: &Answers,
+    out: &mut dyn Write,
+) -> std::io::Result<()> {
+    let report = Report {
+        year: year.into_inner(),
+        parts: part_reports(outcomes, answers),
+    };
+
+    serde_json::to_writer_pretty(&mut *out, &report)?;
+
+    writeln!(out)
+}
+
+/// Writes `outcomes` of `year` as `JUnit` XML, comparing each answer with the known one in `answers`.
+///
+/// Parts without a known answer are skipped.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn write_junit(
+    year: Year,
+    outcomes: &[DayOutcome],
+    answers: &Answers,
+    out: &mut dyn Write,
+) -> std::io::Result<()> {
+    let reports = part_reports(outcomes, answers);
+
+    let count = |status| {
+        reports
+            .iter()
+            .filter(|report| report.status == status)
+            .count()
+    };
+
+    let total = outcomes
+        .iter()
+        .map(|outcome| {
+            outcome.parse
+                + outcome
+                    .parts
+                    .iter()
+                    .flatten()
+                    .map(|part| part.solve)
+                    .sum::<Duration>()
+        })
+        .sum::<Duration>();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites name="aoc {}" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+        year,
+        reports.len(),
+        count(Status::Failed),
+        count(Status::Error),
+        count(Status::Unknown),
+        total.as_secs_f64()
+    )?;
+
+    for outcome in outcomes {
+        let day = outcome.day.into_inner();
+
+        let cases = reports
+            .iter()
+            .filter(|report| report.day == day)
+            .collect::<Vec<_>>();
+
+        let count = |status| {
+            cases
+                .iter()
+                .filter(|report| report.status == status)
+                .count()
+        };
+
+        writeln!(
+            out,
+            r#"  <testsuite name="{} day {}" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+            year,
+            outcome.day,
+            cases.len(),
+            count(Status::Failed),
+            count(Status::Error),
+            count(Status::Unknown),
+            outcome.parse.as_secs_f64()
+                + cases.iter().map(|report| report.solve_seconds).sum::<f64>()
+        )?;
+
+        for case in cases {
+            let name = case
+                .part
+                .map_or_else(|| "input".to_owned(), |part| format!("part {}", part));
+
+            write!(
+                out,
+                r#"    <testcase classname="aoc.{}.day{}" name="{}" time="{:.6}""#,
+                year, outcome.day, name, case.solve_seconds
+            )?;
+
+            match case.status {
+                Status::Passed => writeln!(out, "/>")?,
+                Status::Failed => writeln!(
+                    out,
+                    r#"><failure message="expected {}, got {}"/></testcase>"#,
+                    escape(&display(case.expected)),
+                    escape(&display(case.answer))
+                )?,
+                Status::Unknown => writeln!(
+                    out,
+                    r#"><skipped message="no known answer, got {}"/></testcase>"#,
+                    escape(&display(case.answer))
+                )?,
+                Status::Error => writeln!(
+                    out,
+                    r#"><error message="{}"/></testcase>"#,
+                    escape(case.error.as_deref().unwrap_or_default())
+                )?,
+            }
+        }
+
+        writeln!(out, "  </testsuite>")?;
+    }
+
+    writeln!(out, "</testsuites>")
+}
+
+fn display(solution: Option<&PartSolution>) -> String {
+    solution.map_or_else(String::new, |solution| {
+        solution.to_string().trim().to_owned()
+    })
+}
+
+/// Escapes `text` for use in an XML attribute.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::PartSolution;
+    use crate::shared::answers::Answers;
+    use crate::shared::error::SolveError;
+    use crate::shared::report::{has_failures, write_json, write_junit};
+    use crate::shared::runner::{DayOutcome, Part, PartOutcome};
+    use crate::{day, year};
+
+    const ANSWERS: &str = "[01.inputs]\npart_1 = 24\npart_2 = 45\n";
+
+    fn outcomes() -> Vec<DayOutcome> {
+        vec![
+            DayOutcome {
+                day: day!(1),
+                parse: Duration::from_millis(1),
+                parts: Ok(vec![
+                    PartOutcome {
+                        part: Part::One,
+                        solution: Ok(PartSolution::USize(24)),
+                        solve: Duration::from_millis(2),
+                        memory: None,
+                    },
+                    PartOutcome {
+                        part: Part::Two,
+                        solution: Ok(PartSolution::USize(44)),
+                        solve: Duration::from_millis(3),
+                        memory: None,
+                    },
+                ]),
+            },
+            DayOutcome {
+                day: day!(2),
+                parse: Duration::ZERO,
+                parts: Err(SolveError::assumption("A & B < C")),
+            },
+        ]
+    }
+
+    #[test]
+    fn json() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        let mut out = Vec::new();
+        write_json(year!(2022), &outcomes(), &answers, &mut out).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(2022, json["year"]);
+        assert_eq!("passed", json["parts"][0]["status"]);
+        assert_eq!(24, json["parts"][0]["answer"]);
+        assert_eq!("failed", json["parts"][1]["status"]);
+        assert_eq!(45, json["parts"][1]["expected"]);
+        assert_eq!("0.003", json["parts"][1]["solve_seconds"].to_string());
+        assert_eq!("error", json["parts"][2]["status"]);
+        assert!(json["parts"][2]["part"].is_null(), "the whole day failed");
+        assert!(has_failures(&outcomes(), &answers), "part 2 is wrong");
+    }
+
+    #[test]
+    fn junit() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        let mut out = Vec::new();
+        write_junit(year!(2022), &outcomes(), &answers, &mut out).unwrap();
+
+        let xml = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="aoc 2022" tests="3" failures="1" errors="1" skipped="0" time="0.006000">
+  <testsuite name="2022 day 01" tests="2" failures="1" errors="0" skipped="0" time="0.006000">
+    <testcase classname="aoc.2022.day01" name="part 1" time="0.002000"/>
+    <testcase classname="aoc.2022.day01" name="part 2" time="0.003000"><failure message="expected 45, got 44"/></testcase>
+  </testsuite>
+  <testsuite name="2022 day 02" tests="1" failures="0" errors="1" skipped="0" time="0.000000">
+    <testcase classname="aoc.2022.day02" name="input" time="0.000000"><error message="puzzle assumption violated: A &amp; B &lt; C"/></testcase>
+  </testsuite>
+</testsuites>
+"#,
+            xml
+        );
+    }
+}
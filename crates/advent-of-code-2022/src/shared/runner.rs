@@ -0,0 +1,661 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use super::context::{DeclaredParam, SolveContext};
+use super::day::{Day, PuzzleId, Year};
+use super::error::SolveError;
+use super::input::InputSource;
+use super::memory::{MemoryUsage, measure};
+use super::{PartSolution, TryParts};
+
+/// Maps every implemented puzzle, of any [`Year`], onto its solution.
+pub type Registry = BTreeMap<PuzzleId, Box<dyn Solver>>;
+
+/// A [`TryParts`] with its input type erased, so days with different inputs fit in one [`Registry`].
+///
+/// Solvers are shared between the worker threads of [`run_parallel`](super::pool::run_parallel).
+pub trait Solver: Send + Sync {
+    /// Parses `input` once, then solves each of `parts` against it in `context`, timing every stage.
+    fn solve(&self, day: Day, input: &str, parts: &[Part], context: &SolveContext) -> DayOutcome;
+
+    /// Parses `input` for [`Solver::solve_parsed`], so that parts solved apart, even on other threads, share it.
+    ///
+    /// # Errors
+    ///
+    /// When `input` is malformed.
+    fn parse(&self, input: &str) -> Result<ParsedInput, SolveError>;
+
+    /// Solves `part` against `input` in `context`, timing it.
+    ///
+    /// # Panics
+    ///
+    /// When `input` was parsed by another solver.
+    fn solve_parsed(
+        &self,
+        day: Day,
+        input: &ParsedInput,
+        part: Part,
+        context: &SolveContext,
+    ) -> PartOutcome;
+
+    /// Parses `input` once, then solves `part` against it in `context` again and again, handing every solution and how
+    /// long it took to `each` until that breaks. Parsing is left out, so that a profiler sees the part alone.
+    ///
+    /// # Errors
+    ///
+    /// When `input` cannot be parsed.
+    fn repeat(
+        &self,
+        day: Day,
+        input: &str,
+        part: Part,
+        context: &SolveContext,
+        each: &mut dyn FnMut(Result<PartSolution, SolveError>, Duration) -> ControlFlow<()>,
+    ) -> Result<(), SolveError>;
+
+    /// The puzzle parameters the day declares, see [`TryParts::params`].
+    fn params(&self) -> Vec<DeclaredParam>;
+}
+
+impl<T: TryParts + Send + Sync> Solver for T
+where
+    T::Input: Send + Sync + 'static,
+{
+    fn solve(&self, day: Day, input: &str, parts: &[Part], context: &SolveContext) -> DayOutcome {
+        let start = Instant::now();
+        let input = self.try_parse(input);
+        let parse = start.elapsed();
+
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => {
+                return DayOutcome {
+                    day,
+                    parse,
+                    parts: Err(error),
+                };
+            },
+        };
+
+        let parts = parts
+            .iter()
+            .map(|&part| solve_part(self, day, &input, part, context))
+            .collect();
+
+        DayOutcome {
+            day,
+            parse,
+            parts: Ok(parts),
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<ParsedInput, SolveError> {
+        let input = self.try_parse(input)?;
+
+        Ok(ParsedInput(Box::new(input)))
+    }
+
+    fn solve_parsed(
+        &self,
+        day: Day,
+        input: &ParsedInput,
+        part: Part,
+        context: &SolveContext,
+    ) -> PartOutcome {
+        let input = input
+            .0
+            .downcast_ref::<T::Input>()
+            .expect("the input is parsed by the same solver");
+
+        solve_part(self, day, input, part, context)
+    }
+
+    fn repeat(
+        &self,
+        day: Day,
+        input: &str,
+        part: Part,
+        context: &SolveContext,
+        each: &mut dyn FnMut(Result<PartSolution, SolveError>, Duration) -> ControlFlow<()>,
+    ) -> Result<(), SolveError> {
+        let input = self.try_parse(input)?;
+        let context = context.for_part(day, part);
+
+        loop {
+            let start = Instant::now();
+            let solution = part.solve(self, &input, &context);
+            let solve = start.elapsed();
+
+            if each(solution, solve).is_break() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn params(&self) -> Vec<DeclaredParam> {
+        TryParts::params(self)
+    }
+}
+
+/// Solves `part` of `solution` against the parsed `input` in `context`, timing it and counting its allocations.
+fn solve_part<T: TryParts + ?Sized>(
+    solution: &T,
+    day: Day,
+    input: &T::Input,
+    part: Part,
+    context: &SolveContext,
+) -> PartOutcome {
+    let start = Instant::now();
+    let (solution, memory) = measure(|| part.solve(solution, input, &context.for_part(day, part)));
+    let solve = start.elapsed();
+
+    PartOutcome {
+        part,
+        solution,
+        solve,
+        memory,
+    }
+}
+
+/// An input parsed by [`Solver::parse`], of a type only the solver knows.
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Runs this part of `solution` against the parsed `input` in `context`.
+    ///
+    /// # Errors
+    ///
+    /// When the solution fails on `input`.
+    pub fn solve<T: TryParts + ?Sized>(
+        self,
+        solution: &T,
+        input: &T::Input,
+        context: &SolveContext,
+    ) -> Result<PartSolution, SolveError> {
+        match self {
+            Part::One => solution.try_part_1(input, context),
+            Part::Two => solution.try_part_2(input, context),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(PartFromStrError {}),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug)]
+pub struct PartFromStrError {}
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting part 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The days to run, as given on the command line.
+///
+/// Accepts a single day (`7`), an inclusive range (`1..=20`) or an exclusive range (`1..21`).
+///
+/// ```
+/// # use advent_of_code_2022::day;
+/// # use advent_of_code_2022::shared::runner::DaySelection;
+/// let selection: DaySelection = "1..=20".parse().unwrap();
+/// assert!(selection.contains(day!(20)));
+/// assert!(!selection.contains(day!(21)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Single(Day),
+    Range { first: Day, last: Day },
+}
+
+impl DaySelection {
+    /// Whether `day` is part of this selection.
+    #[must_use]
+    pub fn contains(&self, day: Day) -> bool {
+        match *self {
+            DaySelection::All => true,
+            DaySelection::Single(single) => single == day,
+            DaySelection::Range { first, last } => (first..=last).contains(&day),
+        }
+    }
+
+    /// The smallest selection of every one of `days`: the single day, or the range from the first to the last of them.
+    #[must_use]
+    pub fn spanning<I: IntoIterator<Item = Day>>(days: I) -> Option<Self> {
+        let (first, last) = days.into_iter().fold(None, |span, day| match span {
+            Some((first, last)) => Some((Ord::min(first, day), Ord::max(last, day))),
+            None => Some((day, day)),
+        })?;
+
+        Some(if first == last {
+            DaySelection::Single(first)
+        } else {
+            DaySelection::Range { first, last }
+        })
+    }
+
+    /// This selection as it is passed on the command line, like `7` or `1..=20`, which [`DaySelection::All`] is not.
+    #[must_use]
+    pub fn argument(&self) -> Option<String> {
+        match *self {
+            DaySelection::All => None,
+            DaySelection::Single(day) => Some(day.to_string()),
+            DaySelection::Range { first, last } => Some(format!("{}..={}", first, last)),
+        }
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DaySelection::All => f.write_str("all days"),
+            DaySelection::Single(day) => write!(f, "day {}", day),
+            DaySelection::Range { first, last } => write!(f, "days {} to {}", first, last),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let Some((first, rest)) = s.split_once("..") else {
+            return s
+                .parse()
+                .map(DaySelection::Single)
+                .map_err(|_| DaySelectionFromStrError {});
+        };
+
+        let first: Day = first.parse().map_err(|_| DaySelectionFromStrError {})?;
+
+        let last: Day = if let Some(last) = rest.strip_prefix('=') {
+            last.parse().map_err(|_| DaySelectionFromStrError {})?
+        } else {
+            // exclusive range, the day before the given end is the last one
+            let end: u8 = rest.parse().map_err(|_| DaySelectionFromStrError {})?;
+
+            end.checked_sub(1)
+                .and_then(Day::try_new)
+                .ok_or(DaySelectionFromStrError {})?
+        };
+
+        if first > last {
+            return Err(DaySelectionFromStrError {});
+        }
+
+        Ok(DaySelection::Range { first, last })
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`].
+#[derive(Debug)]
+pub struct DaySelectionFromStrError {}
+
+impl Error for DaySelectionFromStrError {}
+
+impl Display for DaySelectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a day (`7`) or an ascending range of days (`1..=20` or `1..21`)")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The result of solving one part of a day.
+#[derive(Debug)]
+pub struct PartOutcome {
+    pub part: Part,
+    pub solution: Result<PartSolution, SolveError>,
+    /// Time spent in the part itself.
+    pub solve: Duration,
+    /// What the part allocated, when the `count-allocations` feature is enabled.
+    pub memory: Option<MemoryUsage>,
+}
+
+/// The results of solving the requested parts of a day.
+#[derive(Debug)]
+pub struct DayOutcome {
+    pub day: Day,
+    /// Time spent reading and parsing the input, shared by all parts.
+    pub parse: Duration,
+    /// The requested parts, or why the input could not be prepared for them.
+    pub parts: Result<Vec<PartOutcome>, SolveError>,
+}
+
+impl DayOutcome {
+    /// Every error that occurred while solving this day, with the part it occurred in, if any.
+    #[must_use]
+    pub fn errors(&self) -> Box<dyn Iterator<Item = (Option<Part>, &SolveError)> + '_> {
+        match self.parts {
+            Ok(ref parts) => Box::new(parts.iter().filter_map(|part| {
+                part.solution
+                    .as_ref()
+                    .err()
+                    .map(|error| (Some(part.part), error))
+            })),
+            Err(ref error) => Box::new(std::iter::once((None, error))),
+        }
+    }
+
+    /// Writes the timings of this day and the solution of each part to `out`.
+    ///
+    /// # Errors
+    ///
+    /// When writing to `out` fails.
+    pub fn write_solutions(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        match self.parts {
+            Ok(ref parts) => {
+                writeln!(out, "Day {} (parse: {:.2?})", self.day, self.parse)?;
+
+                for part in parts {
+                    match part.solution {
+                        Ok(ref solution) => writeln!(
+                            out,
+                            "  Part {}: {} (solve: {:.2?})",
+                            part.part, solution, part.solve
+                        )?,
+                        Err(ref error) => writeln!(
+                            out,
+                            "  Part {}: error: {} (solve: {:.2?})",
+                            part.part, error, part.solve
+                        )?,
+                    }
+                }
+            },
+            Err(ref error) => writeln!(out, "Day {}: error: {}", self.day, error)?,
+        }
+
+        Ok(())
+    }
+
+    /// Writes every error of this day to `out`, one per line.
+    ///
+    /// # Errors
+    ///
+    /// When writing to `out` fails.
+    pub fn write_errors(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        for (part, error) in self.errors() {
+            match part {
+                Some(part) => writeln!(out, "Day {}, part {}: {}", self.day, part, error)?,
+                None => writeln!(out, "Day {}: {}", self.day, error)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the input of the puzzle `id` from `source` and solves the requested `parts` of it in `context`, timing every
+/// stage.
+///
+/// # Panics
+///
+/// When the day's solution panics.
+#[must_use]
+pub fn solve_day(
+    id: PuzzleId,
+    solution: &dyn Solver,
+    parts: &[Part],
+    source: &InputSource,
+    context: &SolveContext,
+) -> DayOutcome {
+    let start = Instant::now();
+    let input = source.read("inputs", id);
+    let read = start.elapsed();
+
+    match input {
+        Ok(input) => {
+            let mut outcome = solution.solve(id.day, &input, parts, context);
+
+            outcome.parse += read;
+
+            outcome
+        },
+        Err(error) => DayOutcome {
+            day: id.day,
+            parse: read,
+            parts: Err(error),
+        },
+    }
+}
+
+/// Runs the requested `parts` of every day of `year` in `registry` that is part of `selection`, reading their input from
+/// `source` and solving it in `context`, writing each [`PartSolution`] and its timings to `out` as soon as the day is
+/// done.
+///
+/// Returns the outcome of every day that was run, which is empty when `selection` contains no implemented day.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+///
+/// # Panics
+///
+/// When a day's solution panics.
+pub fn run(
+    registry: &Registry,
+    year: Year,
+    selection: DaySelection,
+    parts: &[Part],
+    source: &InputSource,
+    context: &SolveContext,
+    out: &mut dyn Write,
+) -> std::io::Result<Vec<DayOutcome>> {
+    let mut outcomes = Vec::new();
+
+    for (&id, solution) in registry
+        .iter()
+        .filter(|&(&id, _)| id.year == year && selection.contains(id.day))
+    {
+        let outcome = solve_day(id, solution.as_ref(), parts, source, context);
+
+        outcome.write_solutions(out)?;
+
+        outcomes.push(outcome);
+    }
+
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::ops::ControlFlow;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::PartSolution;
+    use crate::shared::context::SolveContext;
+    use crate::shared::day::PuzzleId;
+    use crate::shared::input::InputSource;
+    use crate::shared::runner::{DaySelection, Part, Registry, Solver as _, run};
+    use crate::shared::testing::{Lines, temp_path};
+    use crate::{day, year};
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!(Ok(DaySelection::Single(day!(7))), "7".parse().map_err(drop));
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let inclusive = DaySelection::Range {
+            first: day!(1),
+            last: day!(20),
+        };
+
+        assert_eq!(Ok(inclusive), "1..=20".parse().map_err(drop));
+        assert_eq!(Ok(inclusive), "1..21".parse().map_err(drop));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for invalid in ["0", "26", "20..=1", "1..1", "1..=26", "a..=b", ""] {
+            assert!(
+                invalid.parse::<DaySelection>().is_err(),
+                "`{}` should not parse",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn arguments_round_trip() {
+        let missing = DaySelection::spanning([day!(12), day!(3), day!(7)]).unwrap();
+
+        assert_eq!(
+            DaySelection::Range {
+                first: day!(3),
+                last: day!(12),
+            },
+            missing
+        );
+
+        for selection in [missing, DaySelection::spanning([day!(7)]).unwrap()] {
+            let argument = selection.argument().unwrap();
+
+            assert_eq!(
+                Ok(selection),
+                argument.parse().map_err(drop),
+                "{}",
+                argument
+            );
+        }
+
+        assert_eq!(None, DaySelection::spanning([]));
+        assert_eq!(None, DaySelection::All.argument());
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!(Ok(Part::One), "1".parse().map_err(drop));
+        assert_eq!(Ok(Part::Two), "2".parse().map_err(drop));
+        assert!("3".parse::<Part>().is_err(), "there is no part 3");
+    }
+
+    #[test]
+    fn runs_selected_year() {
+        let path = temp_path("runner.txt");
+        fs::write(&path, "a\nb\n").unwrap();
+
+        let mut registry = Registry::new();
+        registry.insert(
+            PuzzleId::new(year!(2021), day!(1)),
+            Box::new(Lines::default()),
+        );
+        registry.insert(
+            PuzzleId::new(year!(2022), day!(1)),
+            Box::new(Lines::default()),
+        );
+        registry.insert(
+            PuzzleId::new(year!(2022), day!(2)),
+            Box::new(Lines::default()),
+        );
+
+        let mut out = Vec::new();
+
+        let outcomes = run(
+            &registry,
+            year!(2022),
+            DaySelection::All,
+            &[Part::One],
+            &InputSource::Path(path.clone()),
+            &SolveContext::default(),
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![day!(1), day!(2)],
+            outcomes
+                .iter()
+                .map(|outcome| outcome.day)
+                .collect::<Vec<_>>()
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn repeats_until_broken() {
+        let mut solutions = Vec::new();
+
+        Lines::default()
+            .repeat(
+                day!(1),
+                "a\nb\nc\n",
+                Part::One,
+                &SolveContext::default(),
+                &mut |solution, _| {
+                    solutions.push(solution.unwrap());
+
+                    if solutions.len() < 3 {
+                        ControlFlow::Continue(())
+                    } else {
+                        ControlFlow::Break(())
+                    }
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                PartSolution::from(3_usize),
+                PartSolution::from(3_usize),
+                PartSolution::from(3_usize),
+            ],
+            solutions
+        );
+    }
+}
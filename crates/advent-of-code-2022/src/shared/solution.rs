@@ -1,59 +1,262 @@
-use std::path::Path;
-use std::{env, fs};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::{io, slice};
 
-use super::day::Day;
+use clap::{ArgAction, Parser};
 
-fn read_file_base(filepath: impl AsRef<Path>) -> String {
-    let f = fs::read_to_string(&filepath);
+use super::answers::{Answers, Verdict};
+use super::context::{SolveContext, parse_param};
+use super::day::PuzzleId;
+use super::diagnostics::{Terminal, Verbosity};
+use super::error::SolveError;
+use super::examples::{Example, Manifest};
+use super::input::{DataDirs, InputSource};
+use super::runner::{Part, Solver, solve_day};
+use super::summary::{SummaryOptions, write_summary};
+use super::watch::{check_examples, check_main};
 
-    f.unwrap_or_else(|error| {
-        panic!(
-            "Error reading file \"{}\": {error:?}",
-            filepath.as_ref().to_str().unwrap()
-        )
-    })
+/// Helper function that reads a text file to a string.
+///
+/// # Panics
+///
+/// if the file is in none of the [`DataDirs`] or cannot be read.
+#[must_use]
+pub fn read_file(folder: &str, id: PuzzleId) -> String {
+    try_read_file(folder, id).unwrap_or_else(|error| panic!("{}", error))
 }
 
-/// Helper function that reads a text file to a string.
+/// Like [`read_file`], but returns an error instead of panicking when the file cannot be read.
+///
+/// # Errors
+///
+/// [`SolveError::NotFound`] if the file is in none of the [`DataDirs`], [`SolveError::Io`] if it cannot be read.
+pub fn try_read_file(folder: &str, id: PuzzleId) -> Result<String, SolveError> {
+    InputSource::DataDirs.read(folder, id)
+}
+
+/// Reads every example of the puzzle `id` listed in its [`Manifest`], along with its input.
 ///
 /// # Panics
 ///
-/// if the file does not exist or cannot be read.
+/// If the manifest is invalid, or an example is in none of the [`DataDirs`] or cannot be read.
 #[must_use]
-pub fn read_file(folder: &str, day: &Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{}.txt", day));
+pub fn read_examples(id: PuzzleId) -> Vec<(Example, String)> {
+    let dirs = DataDirs::discover().unwrap_or_else(|error| panic!("{}", error));
+    let manifest = Manifest::load(&dirs, id).unwrap_or_else(|error| panic!("{}", error));
+
+    manifest
+        .examples
+        .into_iter()
+        .map(|example| {
+            let input = dirs
+                .read(&example.path(id))
+                .unwrap_or_else(|error| panic!("{}", error));
+
+            (example, input)
+        })
+        .collect()
+}
+
+/// Asserts that `part` of `solution` gives the known answer to the input of the puzzle `id`, as the tests generated by
+/// [`solution!`](crate::solution!) do. Without a known answer, like right after `aoc new`, or without the input, which
+/// is not checked in, there is nothing to check.
+///
+/// # Panics
+///
+/// When it gives another answer or fails, or the input or the answers cannot be read.
+#[cfg(test)]
+pub fn assert_outcome<T: super::TryParts + ?Sized>(id: PuzzleId, solution: &T, part: Part) {
+    use pretty_assertions::assert_eq;
+
+    use super::answers::answer;
 
-    read_file_base(filepath)
+    let Some(expected) = answer(id, "inputs", part) else {
+        eprintln!("{}, part {}: no known answer to the input", id, part);
+
+        return;
+    };
+
+    let input = match try_read_file("inputs", id) {
+        Ok(input) => input,
+        Err(error @ SolveError::NotFound { .. }) => {
+            eprintln!("{}, part {}: no input, {}", id, part, error);
+
+            return;
+        },
+        Err(error) => panic!("{}", error),
+    };
+
+    let input = solution
+        .try_parse(&input)
+        .unwrap_or_else(|error| panic!("{}", error));
+
+    let solved = part
+        .solve(solution, &input, &SolveContext::default())
+        .unwrap_or_else(|error| panic!("{}", error));
+
+    assert_eq!(*expected, solved, "part {} of the input", part);
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Asserts that `part` of `solution` gives the known answer to every example of the puzzle `id` that has one, as the
+/// tests generated by [`solution!`](crate::solution!) do.
 ///
 /// # Panics
 ///
-/// if the file does not exist or cannot be read.
+/// When it gives another answer or fails, or an example cannot be read.
+#[cfg(test)]
+pub fn assert_examples<T: super::TryParts + ?Sized>(id: PuzzleId, solution: &T, part: Part) {
+    use pretty_assertions::assert_eq;
+
+    for (example, input) in read_examples(id) {
+        let Some(expected) = example.answer(part) else {
+            continue;
+        };
+
+        let context = example.context();
+
+        context
+            .check_params(&solution.params())
+            .unwrap_or_else(|error| panic!("{}: {}", example.set, error));
+
+        let input = solution
+            .try_parse(&input)
+            .unwrap_or_else(|error| panic!("{}: {}", example.set, error));
+
+        let solved = part
+            .solve(solution, &input, &context)
+            .unwrap_or_else(|error| panic!("{}: {}", example.set, error));
+
+        assert_eq!(*expected, solved, "part {} of {}", part, example.set);
+    }
+}
+
+/// Solves a day against its input, then checks it against its examples.
+#[derive(Debug, Parser)]
+pub struct DayArgs {
+    /// Checks the examples and the input against their known answers, as `aoc watch` does.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Overrides a puzzle parameter of the input, like `row=10`. May be repeated.
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, String)>,
+
+    /// Shows more of what the solution does: `-v` for its debug messages, `-vv` for every step.
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Shows nothing of what the solution does, not even its progress.
+    #[arg(short, long, conflicts_with = "verbose")]
+    pub quiet: bool,
+}
+
+impl DayArgs {
+    /// The context to solve the input in, with the overridden puzzle parameters, reporting to the terminal.
+    #[must_use]
+    pub fn context(&self) -> SolveContext {
+        let verbosity = Verbosity::from_flags(self.verbose, self.quiet);
+
+        self.params
+            .iter()
+            .cloned()
+            .collect::<SolveContext>()
+            .with_reporter(Arc::new(Terminal::new(verbosity)))
+    }
+}
+
+/// The `main` of the binary of a day: solves the puzzle `id` with `solver`, or only checks it with `--check`.
+///
+/// Fails when an overridden parameter is not one the day declares, an answer is wrong, an example does not check out, or
+/// solving failed.
+///
+/// # Panics
+///
+/// When stdout or stderr cannot be written to.
 #[must_use]
-pub fn read_file_part(folder: &str, day: &Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{}-{}.txt", day, part));
-
-    read_file_base(filepath)
+pub fn day_main(id: PuzzleId, solver: &dyn Solver) -> ExitCode {
+    let args = DayArgs::parse();
+    let context = args.context();
+
+    if let Err(error) = context.check_params(&solver.params()) {
+        eprintln!("{}: {}", id, error);
+
+        return ExitCode::FAILURE;
+    }
+
+    if args.check {
+        return check_main(id, solver, &context);
+    }
+
+    let outcome = solve_day(id, solver, &Part::ALL, &InputSource::DataDirs, &context);
+
+    write_summary(
+        slice::from_ref(&outcome),
+        &SummaryOptions::default(),
+        &mut io::stdout().lock(),
+    )
+    .expect("Failed to write summary");
+
+    outcome
+        .write_errors(&mut io::stderr().lock())
+        .expect("Failed to write errors");
+
+    // without answers every verdict is unknown, which is no reason to fail
+    let answers = Answers::load(id.year).unwrap_or_else(|error| {
+        eprintln!("No known answers: {}", error);
+
+        Answers::default()
+    });
+
+    let mut wrong = false;
+
+    for part in outcome.parts.as_deref().unwrap_or_default() {
+        if let Ok(ref solution) = part.solution {
+            let verdict = answers.verdict(id.day, "inputs", part.part, solution);
+
+            println!("Part {}: {}", part.part, verdict);
+
+            wrong |= matches!(verdict, Verdict::Wrong { .. });
+        }
+
+        if let Some(memory) = part.memory {
+            println!("Part {}: {}", part.part, memory);
+        }
+    }
+
+    println!();
+
+    // without data directories there are no examples either, which the input already reported
+    let examples = DataDirs::discover().map_or(true, |dirs| {
+        check_examples(id, solver, &dirs, &mut io::stdout().lock())
+            .expect("Failed to write examples")
+    });
+
+    if wrong || !examples || outcome.errors().next().is_some() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
+/// Declares the `Solution` of a day, and its [`Day`](super::day::Day) taken from the name of its module, like `day08`.
+/// The year defaults to [`DEFAULT_YEAR`](super::day::DEFAULT_YEAR).
+///
+/// Also generates the `outcome` and `examples` tests of both parts, which check the `Solution` against the known
+/// answers to the input in `answers.toml` and to the examples in their manifest.
 #[macro_export]
 macro_rules! solution {
     () => {
-        $crate::solution!(PartSolution::None, PartSolution::None);
+        $crate::solution!(@year $crate::shared::day::DEFAULT_YEAR);
     };
-    ($solution_1:expr) => {
-        $crate::solution!($solution_1, PartSolution::None);
+    ($year:literal) => {
+        $crate::solution!(@year $crate::year!($year));
     };
-    ($solution_1:expr, $solution_2:expr) => {
+    (@year $year:expr) => {
+        /// The current year.
+        pub const YEAR: $crate::shared::day::Year = $year;
+
         /// The current day.
-        static DAY: std::sync::LazyLock<$crate::shared::day::Day> =
+        pub static DAY: std::sync::LazyLock<$crate::shared::day::Day> =
             std::sync::LazyLock::new(|| {
                 use std::path::Path;
 
@@ -63,28 +266,39 @@ macro_rules! solution {
                     .expect("No stem found")
                     .to_str()
                     .expect("Invalid str");
+                let day = file_stem.strip_prefix("day").unwrap_or(file_stem);
 
-                std::str::FromStr::from_str(file_stem).expect("Could not convert input to Day")
+                std::str::FromStr::from_str(day).expect("Could not convert input to Day")
             });
 
-        #[expect(clippy::disallowed_macros, reason = "No pretty needed here")]
-        fn main() {
-            // use advent_of_code_2022::template::runner::*;
-            let input = $crate::shared::solution::read_file("inputs", &DAY);
+        /// The current puzzle.
+        pub static PUZZLE: std::sync::LazyLock<$crate::shared::day::PuzzleId> =
+            std::sync::LazyLock::new(|| $crate::shared::day::PuzzleId::new(YEAR, *DAY));
 
-            let part_1_expected_solution: PartSolution = PartSolution::from($solution_1);
+        pub struct Solution {}
 
-            let s = Solution {};
+        #[cfg(test)]
+        mod solution_tests {
+            $crate::solution!(@tests part_1 One);
+            $crate::solution!(@tests part_2 Two);
+        }
+    };
+    (@tests $module:ident $part:ident) => {
+        mod $module {
+            use $crate::shared::runner::Part;
+            use $crate::shared::solution::{assert_examples, assert_outcome};
 
-            assert_eq!(part_1_expected_solution, s.part_1(&input));
+            use super::super::{PUZZLE, Solution};
 
-            let part_2_expected_solution: PartSolution = PartSolution::from($solution_2);
+            #[test]
+            fn outcome() {
+                assert_outcome(*PUZZLE, &Solution {}, Part::$part);
+            }
 
-            assert_eq!(part_2_expected_solution, s.part_2(&input));
-            // run_part(part_one, &input, &DAY, 1);
-            // run_part(part_two, &input, &DAY, 2);
+            #[test]
+            fn examples() {
+                assert_examples(*PUZZLE, &Solution {}, Part::$part);
+            }
         }
-
-        pub struct Solution {}
     };
 }
@@ -0,0 +1,621 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use toml::de::Error as TomlError;
+use toml::ser::Error as TomlSerError;
+
+use super::PartSolution;
+use super::answers::Answer;
+use super::day::{Day, PuzzleId, Year};
+use super::error::SolveError;
+use super::fetch::{FetchError, Fetcher, HttpClient};
+use super::input::DataDirs;
+use super::runner::Part;
+
+/// The file in a year of the data directory where every verdict of that year is kept.
+pub const VERDICTS_FILE: &str = "verdicts.toml";
+
+/// What Advent of Code thought of a submitted answer.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Judgement {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+}
+
+impl Display for Judgement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Judgement::Correct => f.write_str("correct"),
+            Judgement::TooHigh => f.write_str("too high"),
+            Judgement::TooLow => f.write_str("too low"),
+            Judgement::Wrong => f.write_str("wrong"),
+        }
+    }
+}
+
+/// The gist of the page Advent of Code replies to a submission with.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Reply {
+    Judged(Judgement),
+    /// An answer was submitted too recently, and the next one is accepted after this long.
+    Wait(Duration),
+    /// The part was solved already, or is not unlocked yet.
+    WrongLevel,
+}
+
+static WAIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+/// Recognizes the reply to a submission in the page `html`.
+#[must_use]
+pub fn parse_reply(html: &str) -> Option<Reply> {
+    if html.contains("That's the right answer") {
+        Some(Reply::Judged(Judgement::Correct))
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Some(Reply::Judged(Judgement::TooHigh))
+        } else if html.contains("your answer is too low") {
+            Some(Reply::Judged(Judgement::TooLow))
+        } else {
+            Some(Reply::Judged(Judgement::Wrong))
+        }
+    } else if let Some(captures) = WAIT.captures(html) {
+        let number = |index| {
+            captures
+                .get(index)
+                .map_or(Some(0), |number| number.as_str().parse::<u64>().ok())
+        };
+
+        let seconds = number(1)?.checked_mul(60)?.checked_add(number(2)?)?;
+
+        Some(Reply::Wait(Duration::from_secs(seconds)))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Some(Reply::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// Why an answer is not worth submitting, as far as the verdicts so far tell.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// The answer was submitted before.
+    Repeated(Judgement),
+    /// The answer is at least as high as one that was too high.
+    TooHigh { bound: Answer },
+    /// The answer is at most as low as one that was too low.
+    TooLow { bound: Answer },
+    /// The part was solved with another answer.
+    Solved { answer: Answer },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Rejection::Repeated(judgement) => {
+                write!(f, "submitted before, and it was {}", judgement)
+            },
+            Rejection::TooHigh { ref bound } => write!(f, "{} was too high already", bound),
+            Rejection::TooLow { ref bound } => write!(f, "{} was too low already", bound),
+            Rejection::Solved { ref answer } => write!(f, "solved already, with {}", answer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Record {
+    answer: Answer,
+    verdict: Judgement,
+}
+
+/// Every verdict on a submitted answer, kept in [`VERDICTS_FILE`] so no answer is submitted twice.
+#[derive(Debug)]
+pub struct VerdictCache {
+    path: PathBuf,
+    records: BTreeMap<(Day, Part), Vec<Record>>,
+}
+
+impl VerdictCache {
+    /// Loads the [`VERDICTS_FILE`] of `year` from the first of `dirs` that has it, or starts an empty one in the first
+    /// that exists.
+    ///
+    /// # Errors
+    ///
+    /// When there is no data directory, or the file cannot be read or is invalid.
+    pub fn load(dirs: &DataDirs, year: Year) -> Result<Self, SubmitError> {
+        let relative = year.path(VERDICTS_FILE);
+
+        match dirs.resolve(&relative) {
+            Ok(path) => {
+                let contents = fs::read_to_string(&path).map_err(|source| SubmitError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+
+                Self::parse(path, &contents)
+            },
+            Err(SolveError::NotFound { .. }) => Ok(Self {
+                path: dirs
+                    .first_existing()
+                    .map_err(SubmitError::Data)?
+                    .join(relative),
+                records: BTreeMap::new(),
+            }),
+            Err(error) => Err(SubmitError::Data(error)),
+        }
+    }
+
+    /// Parses the `contents` of a verdicts file, which is saved to `path`.
+    ///
+    /// # Errors
+    ///
+    /// When `contents` is not valid TOML, or a key is not a day or a part.
+    pub fn parse(path: PathBuf, contents: &str) -> Result<Self, SubmitError> {
+        let raw: BTreeMap<String, BTreeMap<String, Vec<Record>>> =
+            toml::from_str(contents).map_err(SubmitError::Toml)?;
+
+        let mut records = BTreeMap::new();
+
+        for (day, parts) in raw {
+            let day: Day = day.parse().map_err(|_| SubmitError::Key(day))?;
+
+            for (part, part_records) in parts {
+                let part = match part.as_str() {
+                    "part_1" => Part::One,
+                    "part_2" => Part::Two,
+                    _ => return Err(SubmitError::Key(part)),
+                };
+
+                records.insert((day, part), part_records);
+            }
+        }
+
+        Ok(Self { path, records })
+    }
+
+    /// Writes every verdict back to where it was loaded from, creating its year's folder if need be.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be written.
+    pub fn save(&self) -> Result<(), SubmitError> {
+        let mut raw: BTreeMap<String, BTreeMap<String, &Vec<Record>>> = BTreeMap::new();
+
+        for (&(day, part), records) in &self.records {
+            raw.entry(day.to_string())
+                .or_default()
+                .insert(format!("part_{}", part), records);
+        }
+
+        let contents = toml::to_string(&raw).map_err(SubmitError::Serialize)?;
+
+        let io = |source| SubmitError::Io {
+            path: self.path.clone(),
+            source,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io)?;
+        }
+
+        fs::write(&self.path, contents).map_err(io)
+    }
+
+    /// Checks `answer` to `part` of `day` against the verdicts so far.
+    #[must_use]
+    pub fn check(&self, day: Day, part: Part, answer: &Answer) -> Option<Rejection> {
+        let records = self.records.get(&(day, part))?;
+
+        let candidate = PartSolution::from(answer.clone());
+
+        for record in records {
+            if record.answer == *answer {
+                return Some(Rejection::Repeated(record.verdict));
+            }
+        }
+
+        for record in records {
+            let bound = PartSolution::from(record.answer.clone());
+
+            match record.verdict {
+                Judgement::Correct => {
+                    return Some(Rejection::Solved {
+                        answer: record.answer.clone(),
+                    });
+                },
+                Judgement::TooHigh if candidate >= bound => {
+                    return Some(Rejection::TooHigh {
+                        bound: record.answer.clone(),
+                    });
+                },
+                Judgement::TooLow if candidate <= bound => {
+                    return Some(Rejection::TooLow {
+                        bound: record.answer.clone(),
+                    });
+                },
+                Judgement::TooHigh | Judgement::TooLow | Judgement::Wrong => {},
+            }
+        }
+
+        None
+    }
+
+    /// Remembers that `answer` to `part` of `day` was judged `verdict`.
+    pub fn record(&mut self, day: Day, part: Part, answer: Answer, verdict: Judgement) {
+        self.records
+            .entry((day, part))
+            .or_default()
+            .push(Record { answer, verdict });
+    }
+}
+
+/// What came of [`submit`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submitted {
+    /// The answer was not sent, as the verdicts so far rule it out.
+    Rejected(Rejection),
+    Replied(Reply),
+}
+
+/// Submits `solution` to `part` of the puzzle `id`, unless `cache` rules it out, and records the verdict in `cache`.
+///
+/// `cache` holds the verdicts of the year of `id`.
+///
+/// # Errors
+///
+/// When `solution` cannot be submitted, the request fails, or the reply is not recognized.
+pub fn submit<C: HttpClient>(
+    fetcher: &mut Fetcher<C>,
+    cache: &mut VerdictCache,
+    id: PuzzleId,
+    part: Part,
+    solution: &PartSolution,
+) -> Result<Submitted, SubmitError> {
+    let answer = match Answer::try_from(solution) {
+        Ok(answer @ (Answer::Integer(_) | Answer::Text(_))) => answer,
+        Ok(Answer::Lines(_)) | Err(()) => {
+            return Err(SubmitError::Unsubmittable(solution.to_string()));
+        },
+    };
+
+    if let Some(rejection) = cache.check(id.day, part, &answer) {
+        return Ok(Submitted::Rejected(rejection));
+    }
+
+    let html = fetcher
+        .post_answer(id, part, &answer.to_string())
+        .map_err(SubmitError::Fetch)?;
+
+    let reply = parse_reply(&html).ok_or(SubmitError::UnexpectedReply)?;
+
+    if let Reply::Judged(verdict) = reply {
+        cache.record(id.day, part, answer, verdict);
+        cache.save()?;
+    }
+
+    Ok(Submitted::Replied(reply))
+}
+
+/// Why an answer could not be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    /// Only numbers and single line text can be submitted, so [`PartSolution::Vec`] needs reading first.
+    Unsubmittable(String),
+    Fetch(FetchError),
+    /// The reply to the submission was not recognized.
+    UnexpectedReply,
+    /// There is no data directory to keep the verdicts in.
+    Data(SolveError),
+    Toml(TomlError),
+    Serialize(TomlSerError),
+    /// A key of the verdicts file is not a [`Day`] or a part.
+    Key(String),
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SubmitError::Fetch(ref source) => Some(source),
+            SubmitError::Data(ref source) => Some(source),
+            SubmitError::Toml(ref source) => Some(source),
+            SubmitError::Serialize(ref source) => Some(source),
+            SubmitError::Io { ref source, .. } => Some(source),
+            SubmitError::Unsubmittable(_) | SubmitError::UnexpectedReply | SubmitError::Key(_) => {
+                None
+            },
+        }
+    }
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SubmitError::Unsubmittable(ref solution) => {
+                write!(f, "`{}` cannot be submitted as is", solution)
+            },
+            SubmitError::Fetch(ref source) => Display::fmt(source, f),
+            SubmitError::UnexpectedReply => f.write_str("the reply was not recognized"),
+            SubmitError::Data(ref source) => write!(f, "no data directory: {}", source),
+            SubmitError::Toml(ref source) => write!(f, "invalid verdicts file: {}", source),
+            SubmitError::Serialize(ref source) => {
+                write!(f, "could not serialize verdicts: {}", source)
+            },
+            SubmitError::Key(ref key) => {
+                write!(f, "invalid verdicts file: `{}` is not a day or part", key)
+            },
+            SubmitError::Io {
+                ref path,
+                ref source,
+            } => write!(f, "could not access \"{}\": {}", path.display(), source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::day;
+    use crate::shared::answers::Answer;
+    use crate::shared::fetch::{FetchError, Fetcher, HttpClient, Response, Session};
+    use crate::shared::runner::Part;
+    use crate::shared::submit::{
+        Judgement, Rejection, Reply, SubmitError, Submitted, VerdictCache, parse_reply, submit,
+    };
+    use crate::shared::testing::temp_path;
+    use crate::shared::{Integer, PartSolution};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.</p></article>";
+
+    /// Replies to every submission with the next of its pages, remembering the answers.
+    struct MockServer {
+        pages: RefCell<Vec<&'static str>>,
+        answers: RefCell<Vec<String>>,
+    }
+
+    impl MockServer {
+        fn new(mut pages: Vec<&'static str>) -> Self {
+            pages.reverse();
+
+            Self {
+                pages: RefCell::new(pages),
+                answers: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for &MockServer {
+        fn get(&self, _url: &str, _session: &Session) -> Result<Response, FetchError> {
+            Err(FetchError::Http("not expected".to_owned()))
+        }
+
+        fn post(
+            &self,
+            _url: &str,
+            _session: &Session,
+            form: &[(&str, &str)],
+        ) -> Result<Response, FetchError> {
+            for &(key, value) in form {
+                if key == "answer" {
+                    self.answers.borrow_mut().push(value.to_owned());
+                }
+            }
+
+            Ok(Response {
+                status: 200,
+                body: self.pages.borrow_mut().pop().unwrap().to_owned(),
+            })
+        }
+    }
+
+    fn verdicts_path(name: &str) -> PathBuf {
+        temp_path(&format!("verdicts-{}.toml", name))
+    }
+
+    fn integer(integer: i128) -> Answer {
+        Answer::Integer(Integer::signed(integer))
+    }
+
+    #[test]
+    fn replies() {
+        assert_eq!(
+            Some(Reply::Judged(Judgement::TooHigh)),
+            parse_reply(TOO_HIGH)
+        );
+        assert_eq!(Some(Reply::Judged(Judgement::Correct)), parse_reply(RIGHT));
+        assert_eq!(
+            Some(Reply::Judged(Judgement::Wrong)),
+            parse_reply("<p>That's not the right answer.  If you're stuck, ...</p>")
+        );
+        assert_eq!(
+            Some(Reply::Wait(Duration::from_secs(4 * 60 + 12))),
+            parse_reply("<p>You gave an answer too recently. You have 4m 12s left to wait.</p>")
+        );
+        assert_eq!(
+            Some(Reply::Wait(Duration::from_secs(31))),
+            parse_reply("<p>You gave an answer too recently. You have 31s left to wait.</p>")
+        );
+        assert_eq!(
+            Some(Reply::WrongLevel),
+            parse_reply(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            )
+        );
+        assert_eq!(None, parse_reply("<html></html>"));
+    }
+
+    #[test]
+    fn checks() {
+        let mut cache = VerdictCache::parse(verdicts_path("checks"), "").unwrap();
+
+        cache.record(day!(1), Part::One, integer(100), Judgement::TooHigh);
+        cache.record(day!(1), Part::One, integer(10), Judgement::TooLow);
+        cache.record(day!(1), Part::One, integer(50), Judgement::Wrong);
+
+        assert_eq!(
+            Some(Rejection::Repeated(Judgement::Wrong)),
+            cache.check(day!(1), Part::One, &integer(50))
+        );
+        assert_eq!(
+            Some(Rejection::TooHigh {
+                bound: integer(100)
+            }),
+            cache.check(day!(1), Part::One, &integer(120))
+        );
+        assert_eq!(
+            Some(Rejection::TooLow { bound: integer(10) }),
+            cache.check(day!(1), Part::One, &integer(-3))
+        );
+        assert_eq!(None, cache.check(day!(1), Part::One, &integer(42)));
+        assert_eq!(None, cache.check(day!(1), Part::Two, &integer(120)));
+
+        cache.record(day!(1), Part::One, integer(42), Judgement::Correct);
+
+        assert_eq!(
+            Some(Rejection::Solved {
+                answer: integer(42)
+            }),
+            cache.check(day!(1), Part::One, &integer(43))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = verdicts_path("round-trip");
+
+        let mut cache = VerdictCache::parse(path.clone(), "").unwrap();
+
+        cache.record(
+            day!(5),
+            Part::Two,
+            Answer::Text("ABC".into()),
+            Judgement::Wrong,
+        );
+        cache.record(day!(5), Part::Two, integer(7), Judgement::TooLow);
+        cache.record(day!(5), Part::Two, integer(i128::MIN), Judgement::TooLow);
+        cache.save().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let reloaded = VerdictCache::parse(path.clone(), &contents).unwrap();
+
+        assert_eq!(cache.records, reloaded.records);
+        assert!(
+            matches!(
+                VerdictCache::parse(
+                    path.clone(),
+                    "[[05.part_3]]\nanswer = 1\nverdict = \"wrong\""
+                ),
+                Err(SubmitError::Key(_))
+            ),
+            "there is no part 3"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn submits_each_answer_once() {
+        let path = verdicts_path("submits");
+        let server = MockServer::new(vec![TOO_HIGH, RIGHT]);
+
+        let mut fetcher = Fetcher::new(
+            &server,
+            Session::new("secret"),
+            "http://localhost",
+            Duration::ZERO,
+        );
+        let mut cache = VerdictCache::parse(path.clone(), "").unwrap();
+
+        assert_eq!(
+            Submitted::Replied(Reply::Judged(Judgement::TooHigh)),
+            submit(
+                &mut fetcher,
+                &mut cache,
+                day!(2).into(),
+                Part::One,
+                &PartSolution::U64(500)
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Submitted::Rejected(Rejection::Repeated(Judgement::TooHigh)),
+            submit(
+                &mut fetcher,
+                &mut cache,
+                day!(2).into(),
+                Part::One,
+                &PartSolution::I32(500)
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Submitted::Rejected(Rejection::TooHigh {
+                bound: integer(500)
+            }),
+            submit(
+                &mut fetcher,
+                &mut cache,
+                day!(2).into(),
+                Part::One,
+                &PartSolution::USize(501)
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Submitted::Replied(Reply::Judged(Judgement::Correct)),
+            submit(
+                &mut fetcher,
+                &mut cache,
+                day!(2).into(),
+                Part::One,
+                &PartSolution::I64(499)
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            vec!["500".to_owned(), "499".to_owned()],
+            *server.answers.borrow()
+        );
+
+        let saved = VerdictCache::parse(path.clone(), &fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(cache.records, saved.records);
+        assert!(
+            matches!(
+                submit(
+                    &mut fetcher,
+                    &mut cache,
+                    day!(2).into(),
+                    Part::Two,
+                    &PartSolution::None
+                ),
+                Err(SubmitError::Unsubmittable(_))
+            ),
+            "there is nothing to submit"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
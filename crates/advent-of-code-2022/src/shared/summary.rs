@@ -0,0 +1,335 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::PartSolution;
+use super::day::Day;
+use super::error::SolveError;
+use super::runner::{DayOutcome, Part};
+
+/// The widest an answer gets in the summary table before being cut off.
+const ANSWER_WIDTH: usize = 20;
+
+/// How the rows of the summary table are ordered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// By day, then by part.
+    #[default]
+    Day,
+    /// Slowest part first.
+    Slowest,
+}
+
+impl FromStr for SortOrder {
+    type Err = SortOrderFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "day" => Ok(SortOrder::Day),
+            "slowest" => Ok(SortOrder::Slowest),
+            _ => Err(SortOrderFromStrError {}),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`SortOrder`].
+#[derive(Debug)]
+pub struct SortOrderFromStrError {}
+
+impl Error for SortOrderFromStrError {}
+
+impl Display for SortOrderFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `day` or `slowest`")
+    }
+}
+
+/// Parses a duration with a unit suffix, like `250ms`, `1.5s` or `800us`.
+///
+/// # Errors
+///
+/// When the number is invalid or the unit is not one of `ns`, `us`, `ms` or `s`.
+pub fn parse_duration(s: &str) -> Result<Duration, DurationFromStrError> {
+    let s = s.trim();
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .ok_or(DurationFromStrError {})?;
+
+    let (value, unit) = s.split_at(split);
+
+    let value: f64 = value.parse().map_err(|_| DurationFromStrError {})?;
+
+    let seconds = match unit {
+        "ns" => value / 1_000_000_000.0,
+        "us" => value / 1_000_000.0,
+        "ms" => value / 1_000.0,
+        "s" => value,
+        _ => return Err(DurationFromStrError {}),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| DurationFromStrError {})
+}
+
+/// An error which can be returned when parsing a duration with [`parse_duration`].
+#[derive(Debug)]
+pub struct DurationFromStrError {}
+
+impl Error for DurationFromStrError {}
+
+impl Display for DurationFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a duration like `250ms`, `1.5s` or `800us`")
+    }
+}
+
+/// Which rows the summary table shows, and in what order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SummaryOptions {
+    pub sort: SortOrder,
+    /// Hides parts that were solved faster than this.
+    pub slower_than: Option<Duration>,
+}
+
+struct Row {
+    day: Day,
+    /// [`None`] when the day failed before any part ran.
+    part: Option<Part>,
+    answer: String,
+    parse: Duration,
+    solve: Duration,
+}
+
+/// Renders a [`PartSolution`] on a single line that fits the table.
+fn summarize(solution: Result<&PartSolution, &SolveError>) -> String {
+    let Ok(solution) = solution else {
+        return "<error>".to_owned();
+    };
+
+    let summary = match *solution {
+        PartSolution::Vec(ref lines) => format!("<{} lines>", lines.len()),
+        PartSolution::I32(_)
+        | PartSolution::U32(_)
+        | PartSolution::I64(_)
+        | PartSolution::U64(_)
+        | PartSolution::I128(_)
+        | PartSolution::U128(_)
+        | PartSolution::ISize(_)
+        | PartSolution::USize(_)
+        | PartSolution::String(_)
+        | PartSolution::Manual
+        | PartSolution::None => solution.to_string(),
+    };
+
+    if summary.chars().count() > ANSWER_WIDTH {
+        let mut truncated = summary.chars().take(ANSWER_WIDTH - 3).collect::<String>();
+        truncated.push_str("...");
+        truncated
+    } else {
+        summary
+    }
+}
+
+/// Writes a table of every solved part with its answer and timings, followed by the total time spent.
+///
+/// The total always covers every part in `outcomes`, even the ones hidden by [`SummaryOptions::slower_than`].
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn write_summary(
+    outcomes: &[DayOutcome],
+    options: &SummaryOptions,
+    out: &mut dyn Write,
+) -> std::io::Result<()> {
+    let mut rows = outcomes
+        .iter()
+        .flat_map(|outcome| match outcome.parts {
+            Ok(ref parts) => parts
+                .iter()
+                .map(|part| Row {
+                    day: outcome.day,
+                    part: Some(part.part),
+                    answer: summarize(part.solution.as_ref()),
+                    parse: outcome.parse,
+                    solve: part.solve,
+                })
+                .collect::<Vec<_>>(),
+            Err(ref error) => vec![Row {
+                day: outcome.day,
+                part: None,
+                answer: summarize(Err(error)),
+                parse: outcome.parse,
+                solve: Duration::ZERO,
+            }],
+        })
+        .collect::<Vec<_>>();
+
+    let total_parse = outcomes
+        .iter()
+        .map(|outcome| outcome.parse)
+        .sum::<Duration>();
+    let total_solve = rows.iter().map(|row| row.solve).sum::<Duration>();
+    let total_rows = rows.len();
+
+    if let Some(slower_than) = options.slower_than {
+        rows.retain(|row| row.solve >= slower_than);
+    }
+
+    match options.sort {
+        SortOrder::Day => rows.sort_by_key(|row| (row.day, row.part)),
+        SortOrder::Slowest => rows.sort_by_key(|row| std::cmp::Reverse(row.solve)),
+    }
+
+    writeln!(
+        out,
+        "{:>3}  {:>4}  {:<width$}  {:>12}  {:>12}",
+        "Day",
+        "Part",
+        "Answer",
+        "Parse",
+        "Solve",
+        width = ANSWER_WIDTH
+    )?;
+
+    for row in &rows {
+        writeln!(
+            out,
+            "{:>3}  {:>4}  {:<width$}  {:>12}  {:>12}",
+            row.day.to_string(),
+            row.part
+                .map_or_else(|| "-".to_owned(), |part| part.to_string()),
+            row.answer,
+            format!("{:.2?}", row.parse),
+            format!("{:.2?}", row.solve),
+            width = ANSWER_WIDTH
+        )?;
+    }
+
+    if rows.len() != total_rows {
+        writeln!(out, "({} faster parts hidden)", total_rows - rows.len())?;
+    }
+
+    writeln!(
+        out,
+        "Total: {:.2?} (parse: {:.2?}, solve: {:.2?})",
+        total_parse + total_solve,
+        total_parse,
+        total_solve
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::day;
+    use crate::shared::PartSolution;
+    use crate::shared::error::SolveError;
+    use crate::shared::runner::{DayOutcome, Part, PartOutcome};
+    use crate::shared::summary::{SortOrder, SummaryOptions, parse_duration, write_summary};
+
+    fn outcomes() -> Vec<DayOutcome> {
+        vec![
+            DayOutcome {
+                day: day!(1),
+                parse: Duration::from_millis(1),
+                parts: Ok(vec![
+                    PartOutcome {
+                        part: Part::One,
+                        solution: Ok(PartSolution::U32(24000)),
+                        solve: Duration::from_millis(2),
+                        memory: None,
+                    },
+                    PartOutcome {
+                        part: Part::Two,
+                        solution: Ok(PartSolution::U32(45000)),
+                        solve: Duration::from_millis(30),
+                        memory: None,
+                    },
+                ]),
+            },
+            DayOutcome {
+                day: day!(6),
+                parse: Duration::from_millis(1),
+                parts: Err(SolveError::assumption("no marker")),
+            },
+            DayOutcome {
+                day: day!(10),
+                parse: Duration::from_millis(1),
+                parts: Ok(vec![PartOutcome {
+                    part: Part::Two,
+                    solution: Ok(PartSolution::Vec(vec!["#..#".into(), ".##.".into()])),
+                    solve: Duration::from_millis(5),
+                    memory: None,
+                }]),
+            },
+        ]
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(Duration::from_millis(250), parse_duration("250ms").unwrap());
+        assert_eq!(Duration::from_millis(1500), parse_duration("1.5s").unwrap());
+        assert_eq!(Duration::from_micros(800), parse_duration("800us").unwrap());
+        assert_eq!(Duration::from_nanos(10), parse_duration("10ns").unwrap());
+
+        for invalid in ["", "10", "ms", "10 minutes", "-1s"] {
+            assert!(
+                parse_duration(invalid).is_err(),
+                "`{}` should not parse",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_by_day() {
+        let mut out = Vec::new();
+
+        write_summary(&outcomes(), &SummaryOptions::default(), &mut out).unwrap();
+
+        let expected = [
+            "Day  Part  Answer                       Parse         Solve",
+            " 01     1  24000                       1.00ms        2.00ms",
+            " 01     2  45000                       1.00ms       30.00ms",
+            " 06     -  <error>                     1.00ms        0.00ns",
+            " 10     2  <2 lines>                   1.00ms        5.00ms",
+            "Total: 40.00ms (parse: 3.00ms, solve: 37.00ms)",
+        ];
+
+        assert_eq!(
+            format!("{}\n", expected.join("\n")),
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn slowest_and_filtered() {
+        let mut out = Vec::new();
+
+        let options = SummaryOptions {
+            sort: SortOrder::Slowest,
+            slower_than: Some(Duration::from_millis(5)),
+        };
+
+        write_summary(&outcomes(), &options, &mut out).unwrap();
+
+        let expected = [
+            "Day  Part  Answer                       Parse         Solve",
+            " 01     2  45000                       1.00ms       30.00ms",
+            " 10     2  <2 lines>                   1.00ms        5.00ms",
+            "(2 faster parts hidden)",
+            "Total: 40.00ms (parse: 3.00ms, solve: 37.00ms)",
+        ];
+
+        assert_eq!(
+            format!("{}\n", expected.join("\n")),
+            String::from_utf8(out).unwrap()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Fixtures shared by the tests of several modules.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{env, process};
+
+use super::context::{DeclaredParam, Param, SolveContext};
+use super::{PartSolution, Parts};
+
+/// Added to both answers of [`Lines`].
+pub const EXTRA: Param<usize> = Param::new("extra", 0);
+
+/// A path in the temporary directory for `name`, like `aoc-1234-pool.txt`, which other test runs do not share.
+#[must_use]
+pub fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("aoc-{}-{}", process::id(), name))
+}
+
+/// A day which counts the lines of its input in part 1, and adds up their lengths in part 2, both plus [`EXTRA`].
+#[derive(Default)]
+pub struct Lines {
+    /// Whether part 2 panics instead.
+    pub panics: bool,
+    /// How many inputs were parsed.
+    pub parsed: AtomicUsize,
+}
+
+impl Parts for Lines {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        self.parsed.fetch_add(1, Ordering::Relaxed);
+
+        input.lines().map(ToOwned::to_owned).collect()
+    }
+
+    fn part_1(&self, input: &Self::Input, context: &SolveContext) -> PartSolution {
+        (input.len() + context.get(&EXTRA)).into()
+    }
+
+    fn part_2(&self, input: &Self::Input, context: &SolveContext) -> PartSolution {
+        assert!(!self.panics, "part 2 panics");
+
+        (input.iter().map(String::len).sum::<usize>() + context.get(&EXTRA)).into()
+    }
+
+    fn params(&self) -> Vec<DeclaredParam> {
+        vec![EXTRA.declare()]
+    }
+}
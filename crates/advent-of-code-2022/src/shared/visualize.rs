@@ -0,0 +1,667 @@
+use std::error::Error;
+use std::fmt::{Display, Write as _};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Stderr, Write as _, stderr};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::day::Day;
+use super::runner::Part;
+
+/// How long each frame is shown, in the terminal and in an asciicast.
+pub const FRAME_DELAY: Duration = Duration::from_millis(40);
+
+/// How many pixels wide and high a cell is in an image.
+const CELL_PIXELS: usize = 4;
+
+/// The color of a [`Cell`], as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const GRAY: Color = Color::rgb(96, 96, 96);
+    pub const WHITE: Color = Color::rgb(230, 230, 230);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(133, 153, 0);
+    pub const YELLOW: Color = Color::rgb(232, 190, 60);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const BROWN: Color = Color::rgb(140, 100, 60);
+
+    #[must_use]
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+/// A character of a [`Frame`], drawn in `color` in the terminal, and as a square of `color` in an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    /// Nothing, on a black background.
+    pub const BLANK: Cell = Cell::new(' ', Color::BLACK);
+
+    #[must_use]
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Self { glyph, color }
+    }
+}
+
+/// A picture of a simulation at one moment, as a grid of [`Cell`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    /// A frame of `width` by `height` [`Cell::BLANK`] cells.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::BLANK; width * height],
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell in `column` of `row`, if it is in the frame.
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<Cell> {
+        self.index(row, column)
+            .and_then(|index| self.cells.get(index))
+            .copied()
+    }
+
+    /// Draws `cell` in `column` of `row`, unless that is outside of the frame, which makes clipping free.
+    pub fn set(&mut self, row: usize, column: usize, cell: Cell) {
+        if let Some(target) = self
+            .index(row, column)
+            .and_then(|index| self.cells.get_mut(index))
+        {
+            *target = cell;
+        }
+    }
+
+    fn index(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.height && column < self.width).then_some(row * self.width + column)
+    }
+
+    /// The rows of the frame, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The frame as text for a terminal, in 24-bit ANSI colors, with every line ending in `\r\n`.
+    #[must_use]
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for row in self.rows() {
+            let mut current = None;
+
+            for cell in row {
+                if current != Some(cell.color) {
+                    current = Some(cell.color);
+
+                    // writing to a `String` cannot fail
+                    let _unused = write!(
+                        ansi,
+                        "\x1b[38;2;{};{};{}m",
+                        cell.color.red, cell.color.green, cell.color.blue
+                    );
+                }
+
+                ansi.push(cell.glyph);
+            }
+
+            ansi.push_str("\x1b[0m\r\n");
+        }
+
+        ansi
+    }
+
+    /// The frame as RGB pixels, row by row, every cell a square of its color.
+    fn pixels(&self) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.cells.len() * CELL_PIXELS * CELL_PIXELS * 3);
+
+        for row in self.rows() {
+            for _ in 0..CELL_PIXELS {
+                for cell in row {
+                    for _ in 0..CELL_PIXELS {
+                        pixels.extend([cell.color.red, cell.color.green, cell.color.blue]);
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+}
+
+/// The state of a simulation, which can be drawn as a [`Frame`].
+///
+/// Solutions hand it to [`SolveContext::visualize`](super::context::SolveContext::visualize) whenever the
+/// simulation takes a step, which only draws it when visualizing.
+pub trait Visualize {
+    fn frame(&self) -> Frame;
+}
+
+/// Where the frames of a part go.
+pub trait Sink: Send {
+    /// Shows or stores `frame`, after every frame before it.
+    ///
+    /// # Errors
+    ///
+    /// When the frame cannot be written.
+    fn write(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Animates the frames in the terminal, on stderr, so that stdout keeps the solutions.
+pub struct TerminalSink {
+    out: Stderr,
+    delay: Duration,
+    started: bool,
+}
+
+impl TerminalSink {
+    #[must_use]
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            out: stderr(),
+            delay,
+            started: false,
+        }
+    }
+}
+
+impl Sink for TerminalSink {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut lock = self.out.lock();
+
+        if !self.started {
+            self.started = true;
+
+            // clear the screen and hide the cursor
+            write!(lock, "\x1b[2J\x1b[?25l")?;
+        }
+
+        write!(lock, "\x1b[H{}", frame.to_ansi())?;
+        lock.flush()?;
+
+        thread::sleep(self.delay);
+
+        Ok(())
+    }
+}
+
+impl Drop for TerminalSink {
+    fn drop(&mut self) {
+        if self.started {
+            // nothing is left to do when the terminal is gone
+            let _unused = writeln!(self.out, "\x1b[?25h");
+        }
+    }
+}
+
+/// The kind of images an [`ImageSink`] writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// The binary PPM format (`P6`), which anything can write.
+    Ppm,
+    Png,
+}
+
+/// Writes every frame to its own numbered image in a directory, like `000001.png`.
+pub struct ImageSink {
+    dir: PathBuf,
+    format: ImageFormat,
+    count: usize,
+}
+
+impl ImageSink {
+    /// A sink writing images in `format` to `dir`, which is created when missing.
+    ///
+    /// # Errors
+    ///
+    /// When `dir` cannot be created.
+    pub fn new(dir: PathBuf, format: ImageFormat) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+}
+
+impl Sink for ImageSink {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        self.count += 1;
+
+        let extension = match self.format {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        };
+
+        let path = self.dir.join(format!("{:06}.{}", self.count, extension));
+        let mut out = BufWriter::new(File::create(path)?);
+
+        let width = frame.width() * CELL_PIXELS;
+        let height = frame.height() * CELL_PIXELS;
+
+        match self.format {
+            ImageFormat::Png => {
+                let too_large = |_| io::Error::other("frame too large for a PNG");
+
+                let mut encoder = png::Encoder::new(
+                    &mut out,
+                    u32::try_from(width).map_err(too_large)?,
+                    u32::try_from(height).map_err(too_large)?,
+                );
+
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+
+                encoder
+                    .write_header()
+                    .and_then(|mut writer| writer.write_image_data(&frame.pixels()))
+                    .map_err(io::Error::other)?;
+            },
+            ImageFormat::Ppm => {
+                write!(out, "P6\n{} {}\n255\n", width, height)?;
+                out.write_all(&frame.pixels())?;
+            },
+        }
+
+        out.flush()
+    }
+}
+
+/// The header of an asciicast, its first line.
+#[derive(Serialize)]
+struct CastHeader {
+    version: u8,
+    width: usize,
+    height: usize,
+}
+
+/// Records the frames as an [asciicast](https://docs.asciinema.org/manual/asciicast/v2/), for `asciinema play`.
+pub struct AsciicastSink {
+    out: BufWriter<File>,
+    delay: Duration,
+    count: u32,
+}
+
+impl AsciicastSink {
+    /// A sink writing the asciicast to `path`, whose directory is created when missing.
+    ///
+    /// # Errors
+    ///
+    /// When `path` cannot be created.
+    pub fn new(path: &Path, delay: Duration) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        Ok(Self {
+            out: BufWriter::new(File::create(path)?),
+            delay,
+            count: 0,
+        })
+    }
+}
+
+impl Sink for AsciicastSink {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        // the terminal is sized after the first frame, as a cast cannot be resized
+        let data = if self.count == 0 {
+            let header = CastHeader {
+                version: 2,
+                width: frame.width(),
+                height: frame.height(),
+            };
+
+            serde_json::to_writer(&mut self.out, &header)?;
+            writeln!(self.out)?;
+
+            format!("\x1b[2J\x1b[H{}", frame.to_ansi())
+        } else {
+            format!("\x1b[H{}", frame.to_ansi())
+        };
+
+        let time = (self.delay * self.count).as_secs_f64();
+
+        serde_json::to_writer(&mut self.out, &(time, "o", data))?;
+        writeln!(self.out)?;
+
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+impl Drop for AsciicastSink {
+    fn drop(&mut self) {
+        // a cast cut short still plays up to where it was cut
+        let _unused = self.out.flush();
+    }
+}
+
+/// The kind of files frames are written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrameFormat {
+    /// An image per frame, in the binary PPM format (`P6`).
+    Ppm,
+    /// An image per frame.
+    #[default]
+    Png,
+    /// An asciicast of every frame.
+    Cast,
+}
+
+impl FromStr for FrameFormat {
+    type Err = FrameFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "ppm" => Ok(FrameFormat::Ppm),
+            "png" => Ok(FrameFormat::Png),
+            "cast" => Ok(FrameFormat::Cast),
+            _ => Err(FrameFormatFromStrError {}),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`FrameFormat`].
+#[derive(Debug)]
+pub struct FrameFormatFromStrError {}
+
+impl Error for FrameFormatFromStrError {}
+
+impl Display for FrameFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `ppm`, `png` or `cast`")
+    }
+}
+
+/// Where to send frames: the terminal, or files in a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Terminal,
+    Directory(PathBuf),
+}
+
+impl FromStr for Target {
+    type Err = TargetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Err(TargetFromStrError {}),
+            "terminal" => Ok(Target::Terminal),
+            path => Ok(Target::Directory(PathBuf::from(path))),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Target`].
+#[derive(Debug)]
+pub struct TargetFromStrError {}
+
+impl Error for TargetFromStrError {}
+
+impl Display for TargetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `terminal` or a directory")
+    }
+}
+
+/// How to visualize the simulations of the parts being solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visualizer {
+    pub target: Target,
+    /// The files frames are written to, in a [`Target::Directory`].
+    pub format: FrameFormat,
+    /// Keeps one frame out of this many, as simulations often take far more steps than are worth watching.
+    pub every: NonZeroUsize,
+}
+
+impl Visualizer {
+    /// Opens the sink for `part` of `day`: in a directory, `14-1/000001.png` or `14-1.cast`.
+    ///
+    /// # Errors
+    ///
+    /// When the files of the sink cannot be created.
+    pub fn sink(&self, day: Day, part: Part) -> io::Result<Box<dyn Sink>> {
+        let name = format!("{}-{}", day, part);
+
+        match (&self.target, self.format) {
+            (&Target::Terminal, _) => Ok(Box::new(TerminalSink::new(FRAME_DELAY))),
+            (&Target::Directory(ref dir), FrameFormat::Cast) => Ok(Box::new(AsciicastSink::new(
+                &dir.join(format!("{}.cast", name)),
+                FRAME_DELAY,
+            )?)),
+            (&Target::Directory(ref dir), FrameFormat::Ppm) => {
+                Ok(Box::new(ImageSink::new(dir.join(name), ImageFormat::Ppm)?))
+            },
+            (&Target::Directory(ref dir), FrameFormat::Png) => {
+                Ok(Box::new(ImageSink::new(dir.join(name), ImageFormat::Png)?))
+            },
+        }
+    }
+}
+
+/// The sink of a part, opened on its first frame, so that parts which do not visualize leave no files.
+enum SinkState {
+    Unopened,
+    Open(Box<dyn Sink>),
+    /// Writing failed, which was reported once.
+    Failed,
+}
+
+/// The frames of one part, sent to the sink of its [`Visualizer`].
+pub struct Recording {
+    visualizer: Visualizer,
+    day: Day,
+    part: Part,
+    state: Mutex<(usize, SinkState)>,
+}
+
+impl Recording {
+    #[must_use]
+    pub fn new(visualizer: Visualizer, day: Day, part: Part) -> Self {
+        Self {
+            visualizer,
+            day,
+            part,
+            state: Mutex::new((0, SinkState::Unopened)),
+        }
+    }
+
+    /// Draws `scene` and writes it to the sink, unless it is not one of the frames kept.
+    ///
+    /// # Errors
+    ///
+    /// When the sink cannot be opened or written to, the first time only, as no more frames are written after that.
+    ///
+    /// # Panics
+    ///
+    /// When a thread panicked while recording.
+    pub fn record<V: Visualize + ?Sized>(&self, scene: &V) -> io::Result<()> {
+        let mut lock = self.state.lock().expect("Recording poisoned");
+        let &mut (ref mut count, ref mut state) = &mut *lock;
+
+        *count += 1;
+
+        if !(*count - 1).is_multiple_of(self.visualizer.every.get()) {
+            return Ok(());
+        }
+
+        if matches!(*state, SinkState::Unopened) {
+            *state = match self.visualizer.sink(self.day, self.part) {
+                Ok(sink) => SinkState::Open(sink),
+                Err(error) => {
+                    *state = SinkState::Failed;
+
+                    return Err(error);
+                },
+            };
+        }
+
+        if let SinkState::Open(ref mut sink) = *state
+            && let Err(error) = sink.write(&scene.frame())
+        {
+            *state = SinkState::Failed;
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::num::NonZeroUsize;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::day;
+    use crate::shared::runner::Part;
+    use crate::shared::testing::temp_path;
+    use crate::shared::visualize::{
+        Cell, Color, Frame, FrameFormat, Recording, Target, Visualize, Visualizer,
+    };
+
+    /// A dot that moves right one column per frame.
+    struct Dot {
+        column: usize,
+    }
+
+    impl Visualize for Dot {
+        fn frame(&self) -> Frame {
+            let mut frame = Frame::new(3, 1);
+
+            frame.set(0, self.column, Cell::new('o', Color::RED));
+
+            frame
+        }
+    }
+
+    #[test]
+    fn frame() {
+        let frame = Dot { column: 1 }.frame();
+
+        assert_eq!(Some(Cell::new('o', Color::RED)), frame.get(0, 1));
+        assert_eq!(Some(Cell::BLANK), frame.get(0, 0));
+        assert_eq!(None, frame.get(1, 0));
+
+        assert_eq!(
+            "\x1b[38;2;0;0;0m \x1b[38;2;220;50;47mo\x1b[38;2;0;0;0m \x1b[0m\r\n",
+            frame.to_ansi()
+        );
+
+        // out of bounds cells are clipped
+        let clipped = Dot { column: 5 }.frame();
+
+        assert_eq!(Frame::new(3, 1), clipped);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Target::Terminal, "terminal".parse().unwrap());
+        assert_eq!(
+            Target::Directory("out/".into()),
+            "out/".parse::<Target>().unwrap()
+        );
+        assert!("".parse::<Target>().is_err(), "there is no target");
+
+        assert_eq!(FrameFormat::Cast, "cast".parse().unwrap());
+        assert!(
+            "gif".parse::<FrameFormat>().is_err(),
+            "gif is not supported"
+        );
+    }
+
+    #[test]
+    fn records_every_other_frame() {
+        let dir = temp_path("visualize");
+
+        for format in [FrameFormat::Ppm, FrameFormat::Png, FrameFormat::Cast] {
+            let recording = Recording::new(
+                Visualizer {
+                    target: Target::Directory(dir.clone()),
+                    format,
+                    every: NonZeroUsize::new(2).unwrap(),
+                },
+                day!(14),
+                Part::Two,
+            );
+
+            for column in 0..3 {
+                recording.record(&Dot { column }).unwrap();
+            }
+        }
+
+        let mut images = fs::read_dir(dir.join("14-2"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+
+        images.sort();
+
+        let ppm = fs::read(dir.join("14-2").join("000001.ppm")).unwrap();
+        let cast = fs::read_to_string(dir.join("14-2.cast")).unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(
+            vec!["000001.png", "000001.ppm", "000002.png", "000002.ppm"],
+            images
+        );
+
+        // 3 by 1 cells of 4 by 4 pixels
+        assert_eq!(b"P6\n12 4\n255\n".len() + 12 * 4 * 3, ppm.len());
+
+        let lines = cast.lines().collect::<Vec<_>>();
+
+        assert_eq!(3, lines.len());
+        assert_eq!(
+            Some(&r#"{"version":2,"width":3,"height":1}"#),
+            lines.first()
+        );
+        assert!(
+            lines
+                .last()
+                .is_some_and(|line| line.starts_with("[0.04,\"o\",")),
+            "the second frame kept is the third one drawn, one delay later"
+        );
+    }
+}
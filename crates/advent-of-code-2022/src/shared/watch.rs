@@ -0,0 +1,379 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use super::PartSolution;
+use super::answers::{Answers, Verdict};
+use super::context::SolveContext;
+use super::day::PuzzleId;
+use super::error::SolveError;
+use super::examples::Manifest;
+use super::input::DataDirs;
+use super::runner::{DayOutcome, Part, Solver};
+
+/// The argument of a day's binary which makes it [`check`] its examples and input, as passed by `aoc watch`.
+///
+/// See [`DayArgs::check`](super::solution::DayArgs::check).
+pub const CHECK_ARGUMENT: &str = "--check";
+
+/// The files a day is solved from: its source, its input, and its examples with their manifest, in every data directory.
+#[derive(Debug)]
+pub struct WatchedFiles {
+    id: PuzzleId,
+    solution: PathBuf,
+    inputs: Vec<PathBuf>,
+    examples: Vec<PathBuf>,
+}
+
+impl WatchedFiles {
+    /// The files of the puzzle `id`, whose source is `solution`.
+    #[must_use]
+    pub fn new(id: PuzzleId, solution: PathBuf, dirs: &DataDirs) -> Self {
+        let folders = |folder| {
+            dirs.dirs()
+                .iter()
+                .map(|dir| dir.join(id.year.path(folder)))
+                .collect()
+        };
+
+        Self {
+            id,
+            solution,
+            inputs: folders("inputs"),
+            examples: folders("examples"),
+        }
+    }
+
+    /// The directories holding the files.
+    ///
+    /// Files are watched through their directory, as editors often replace a file rather than write to it.
+    #[must_use]
+    pub fn directories(&self) -> Vec<&Path> {
+        self.solution
+            .parent()
+            .into_iter()
+            .chain(self.inputs.iter().map(PathBuf::as_path))
+            .chain(self.examples.iter().map(PathBuf::as_path))
+            .collect()
+    }
+
+    /// Whether `path` is one of the files.
+    #[must_use]
+    pub fn contains(&self, path: &Path) -> bool {
+        let (Some(parent), Some(name)) = (
+            path.parent(),
+            path.file_name().and_then(|name| name.to_str()),
+        ) else {
+            return false;
+        };
+
+        let day = self.id.day.to_string();
+
+        if path == self.solution {
+            true
+        } else if self.inputs.iter().any(|dir| dir == parent) {
+            name.strip_suffix(".txt") == Some(&day)
+        } else if self.examples.iter().any(|dir| dir == parent) {
+            // the manifest may list any file, but it is named after the day all the same
+            name.strip_suffix(".txt")
+                .or_else(|| name.strip_suffix(".toml"))
+                .and_then(|stem| stem.strip_prefix(&day))
+                .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with('-'))
+        } else {
+            false
+        }
+    }
+}
+
+/// Solves the parts of the puzzle `id` which an example in its [`Manifest`] has a known answer to against that example,
+/// with its puzzle parameters, writing how every part compares with the answer. A part without one is left out, as
+/// examples may not fit it at all, like the stripes day 10 draws rather than letters.
+///
+/// Returns whether the manifest could be loaded, and every part was solved correctly.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn check_examples(
+    id: PuzzleId,
+    solver: &dyn Solver,
+    dirs: &DataDirs,
+    out: &mut dyn Write,
+) -> std::io::Result<bool> {
+    let manifest = match Manifest::load(dirs, id) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            writeln!(out, "examples: error: {}", error)?;
+
+            return Ok(false);
+        },
+    };
+
+    let mut passed = true;
+
+    for example in manifest.examples {
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|&part| example.answer(part).is_some())
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            continue;
+        }
+
+        let context = example.context();
+
+        let outcome = context
+            .check_params(&solver.params())
+            .and_then(|()| dirs.read(&example.path(id)))
+            .map(|input| solver.solve(id.day, &input, &parts, &context));
+
+        passed &= check_set(&example.set, outcome, |part| example.answer(part), out)?;
+    }
+
+    Ok(passed)
+}
+
+/// [`check_examples`] of the puzzle `id`, then solves both parts against its input in `context`, writing how every part
+/// compares with the known answers.
+///
+/// Returns whether every part was solved, and every part with a known answer was correct.
+///
+/// # Errors
+///
+/// When writing to `out` fails.
+pub fn check(
+    id: PuzzleId,
+    solver: &dyn Solver,
+    dirs: &DataDirs,
+    answers: &Answers,
+    context: &SolveContext,
+    out: &mut dyn Write,
+) -> std::io::Result<bool> {
+    let examples = check_examples(id, solver, dirs, out)?;
+
+    let outcome = dirs
+        .read(&id.path("inputs"))
+        .map(|input| solver.solve(id.day, &input, &Part::ALL, context));
+
+    let inputs = check_set(
+        "inputs",
+        outcome,
+        |part| answers.get(id.day, "inputs", part),
+        out,
+    )?;
+
+    Ok(examples && inputs)
+}
+
+/// Writes how every part of `outcome`, solved against the input `set` unless it could not be read, compares with its
+/// `expected` answer.
+fn check_set<'a>(
+    set: &str,
+    outcome: Result<DayOutcome, SolveError>,
+    expected: impl Fn(Part) -> Option<&'a PartSolution>,
+    out: &mut dyn Write,
+) -> std::io::Result<bool> {
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            writeln!(out, "{}: error: {}", set, error)?;
+
+            return Ok(false);
+        },
+    };
+
+    let parts = match outcome.parts {
+        Ok(parts) => parts,
+        Err(error) => {
+            writeln!(out, "{}: error: {}", set, error)?;
+
+            return Ok(false);
+        },
+    };
+
+    writeln!(out, "{} (parse: {:.2?})", set, outcome.parse)?;
+
+    let mut passed = true;
+
+    for part in parts {
+        match part.solution {
+            Ok(ref solution) => {
+                let verdict = match expected(part.part) {
+                    Some(expected) if expected == solution => Verdict::Correct,
+                    Some(expected) => Verdict::Wrong { expected },
+                    None => Verdict::Unknown,
+                };
+
+                passed &= !matches!(verdict, Verdict::Wrong { .. });
+
+                writeln!(
+                    out,
+                    "  Part {}: {} ({}, solve: {:.2?})",
+                    part.part, solution, verdict, part.solve
+                )?;
+            },
+            Err(ref error) => {
+                writeln!(out, "  Part {}: error: {}", part.part, error)?;
+                passed = false;
+            },
+        }
+    }
+
+    Ok(passed)
+}
+
+/// The `main` of a day's binary when started with [`CHECK_ARGUMENT`]: [`check`]s the puzzle `id` against the known
+/// answers of the data directories, solving its input in `context`.
+#[must_use]
+pub fn check_main(id: PuzzleId, solver: &dyn Solver, context: &SolveContext) -> ExitCode {
+    let dirs = match DataDirs::discover() {
+        Ok(dirs) => dirs,
+        Err(error) => {
+            eprintln!("No data directories: {}", error);
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    // without answers every verdict is unknown, which still shows whether the parts run at all
+    let answers = Answers::load(id.year).unwrap_or_else(|error| {
+        eprintln!("No known answers: {}", error);
+
+        Answers::default()
+    });
+
+    match check(
+        id,
+        solver,
+        &dirs,
+        &answers,
+        context,
+        &mut std::io::stdout().lock(),
+    ) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Failed to write output: {}", error);
+
+            ExitCode::FAILURE
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::answers::Answers;
+    use crate::shared::context::SolveContext;
+    use crate::shared::day::PuzzleId;
+    use crate::shared::input::DataDirs;
+    use crate::shared::testing::{Lines, temp_path};
+    use crate::shared::watch::{WatchedFiles, check};
+    use crate::{day, year};
+
+    #[test]
+    fn contains() {
+        let dirs = DataDirs::single(PathBuf::from("/data"));
+        let files = WatchedFiles::new(
+            PuzzleId::new(year!(2022), day!(8)),
+            PathBuf::from("/src/days/day08.rs"),
+            &dirs,
+        );
+
+        for path in [
+            "/src/days/day08.rs",
+            "/data/2022/inputs/08.txt",
+            "/data/2022/examples/08.txt",
+            "/data/2022/examples/08-2.txt",
+            "/data/2022/examples/08.toml",
+        ] {
+            assert!(files.contains(Path::new(path)), "{} is watched", path);
+        }
+
+        for path in [
+            "/src/days/day09.rs",
+            "/data/2022/inputs/08-2.txt",
+            "/data/2022/inputs/09.txt",
+            "/data/2022/examples/081.txt",
+            "/data/2022/examples/08.txt~",
+            "/data/2021/examples/08.txt",
+        ] {
+            assert!(!files.contains(Path::new(path)), "{} is not watched", path);
+        }
+
+        assert_eq!(
+            vec![
+                Path::new("/src/days"),
+                Path::new("/data/2022/inputs"),
+                Path::new("/data/2022/examples"),
+            ],
+            files.directories()
+        );
+    }
+
+    #[test]
+    fn checks_examples_then_input() {
+        let dir = temp_path("watch");
+        let id = PuzzleId::new(year!(2022), day!(18));
+
+        for (path, contents) in [
+            (
+                "2022/examples/18.toml",
+                "[[examples]]\nfile = \"18-1.txt\"\npart_1 = 2\npart_2 = 2\n\n\
+                 [[examples]]\nfile = \"18-2.txt\"\npart_2 = 4\n\n\
+                 [[examples]]\nfile = \"18-3.txt\"\npart_1 = 11\nparams = { extra = 10 }\n",
+            ),
+            ("2022/examples/18-3.txt", "a\n"),
+            ("2022/examples/18-2.txt", "abc\n"),
+            ("2022/examples/18-1.txt", "a\nb\n"),
+            ("2022/inputs/18.txt", "ab\n"),
+        ] {
+            let path = dir.join(path);
+
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        let dirs = DataDirs::single(dir.clone());
+
+        let answers = Answers::parse("[18.inputs]\npart_1 = 2\n").unwrap();
+
+        let mut out = Vec::new();
+        let passed = check(
+            id,
+            &Lines::default(),
+            &dirs,
+            &answers,
+            &SolveContext::default().with_param("extra", &1),
+            &mut out,
+        )
+        .unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let verdicts = out
+            .lines()
+            .filter_map(|line| line.strip_prefix("  "))
+            .map(|line| line.split(", solve").next().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                "Part 1: 2 (correct",
+                "Part 2: 2 (correct",
+                "Part 2: 3 (wrong, expected 4",
+                "Part 1: 11 (correct",
+                "Part 1: 2 (correct",
+                "Part 2: 3 (unknown",
+            ],
+            verdicts
+        );
+        assert!(!passed, "part 2 of `examples-2` is wrong");
+    }
+}
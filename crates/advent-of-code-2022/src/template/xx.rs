@@ -1,66 +1,21 @@
-use advent_of_code_2022::shared::{PartSolution, Parts};
+use crate::shared::context::SolveContext;
+use crate::shared::{PartSolution, Parts};
 
-advent_of_code_2022::solution!();
+crate::solution!();
 
 impl Parts for Solution {
-    fn part_1(&self, _input: &str) -> PartSolution {
-        None.into()
-    }
+    type Input = Vec<String>;
 
-    fn part_2(&self, _input: &str) -> PartSolution {
-        None.into()
+    fn parse(&self, input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use pretty_assertions::assert_eq;
-
-    mod part_1 {
-        use advent_of_code_2022::shared::solution::read_file;
-        use advent_of_code_2022::shared::{PartSolution, Parts as _};
-        use pretty_assertions::assert_eq;
-
-        use crate::{DAY, Solution};
 
-        #[test]
-        fn outcome() {
-            assert_eq!(
-                PartSolution::None,
-                (Solution {}).part_1(&read_file("inputs", &DAY))
-            );
-        }
-
-        #[test]
-        fn example() {
-            assert_eq!(
-                PartSolution::None,
-                (Solution {}).part_1(&read_file("examples", &DAY))
-            );
-        }
+    fn part_1(&self, _input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        None.into()
     }
 
-    mod part_2 {
-        use advent_of_code_2022::shared::solution::read_file;
-        use advent_of_code_2022::shared::{PartSolution, Parts as _};
-        use pretty_assertions::assert_eq;
-
-        use crate::{DAY, Solution};
-
-        #[test]
-        fn outcome() {
-            assert_eq!(
-                PartSolution::None,
-                (Solution {}).part_2(&read_file("inputs", &DAY))
-            );
-        }
-
-        #[test]
-        fn example() {
-            assert_eq!(
-                PartSolution::None,
-                (Solution {}).part_2(&read_file("examples", &DAY))
-            );
-        }
+    fn part_2(&self, _input: &Self::Input, _context: &SolveContext) -> PartSolution {
+        None.into()
     }
 }
+
@@ -1,16 +1,156 @@
-#![expect(clippy::tests_outside_test_module, reason = "Integration tests")]
-use pretty_assertions::assert_eq;
-
-#[test]
-fn assert_world_ok() {
-    let cls1 = || true;
-    let cls2 = || true;
-    assert_eq!(cls1(), cls2());
+//! Solves every registered day against its input and each of its examples, and checks every part against its known
+//! answer.
+//!
+//! Every day, part and input set is a test of its own, named like `2022/day14/part_2/inputs`, so that
+//! `cargo test --test integration_tests day14` runs a single day.
+
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use advent_of_code_2022::days::registry;
+use advent_of_code_2022::shared::PartSolution;
+use advent_of_code_2022::shared::answers::Answers;
+use advent_of_code_2022::shared::context::SolveContext;
+use advent_of_code_2022::shared::day::{PuzzleId, Year};
+use advent_of_code_2022::shared::examples::{Example, Manifest};
+use advent_of_code_2022::shared::input::DataDirs;
+use advent_of_code_2022::shared::runner::{Part, Registry};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+/// What every test shares: the solutions, where their inputs are, and the answers to them.
+struct Suite {
+    registry: Registry,
+    dirs: DataDirs,
+    answers: Vec<(Year, Answers)>,
+}
+
+impl Suite {
+    fn answers(&self, year: Year) -> &Answers {
+        self.answers
+            .iter()
+            .find(|&&(answers_year, _)| answers_year == year)
+            .map(|&(_, ref answers)| answers)
+            .expect("answers are loaded for every registered year")
+    }
 }
 
-#[test]
-fn assert_world_ok2() {
-    let cls1 = || false;
-    let cls2 = || false;
-    assert_eq!(cls1(), cls2());
+fn main() -> ExitCode {
+    let arguments = Arguments::from_args();
+
+    let registry = registry();
+    let dirs = DataDirs::discover().expect("no data directories");
+
+    let mut answers = Vec::<(Year, Answers)>::new();
+
+    for id in registry.keys() {
+        if answers.iter().all(|&(year, _)| year != id.year) {
+            let loaded = Answers::load(id.year).expect("no known answers");
+
+            answers.push((id.year, loaded));
+        }
+    }
+
+    let suite = Arc::new(Suite {
+        registry,
+        dirs,
+        answers,
+    });
+
+    let trials = suite
+        .registry
+        .keys()
+        .flat_map(|&id| trials(&suite, id))
+        .collect();
+
+    libtest_mimic::run(&arguments, trials).exit_code()
+}
+
+/// The tests of the puzzle `id`: each part against the input, and against every example with a known answer to it.
+fn trials(suite: &Arc<Suite>, id: PuzzleId) -> Vec<Trial> {
+    let manifest =
+        Manifest::load(&suite.dirs, id).unwrap_or_else(|error| panic!("day {}: {}", id.day, error));
+
+    let examples = manifest
+        .examples
+        .into_iter()
+        .map(Arc::new)
+        .collect::<Vec<_>>();
+
+    let mut trials = Vec::new();
+
+    for part in Part::ALL {
+        let suite_for_input = Arc::<Suite>::clone(suite);
+
+        // without an answer there is nothing to check, but the test still shows up as ignored
+        let known = suite.answers(id.year).get(id.day, "inputs", part).is_some();
+
+        trials.push(
+            Trial::test(name(id, part, "inputs"), move || {
+                let suite = suite_for_input;
+                let input = suite.dirs.read(&id.path("inputs"))?;
+                let expected = suite
+                    .answers(id.year)
+                    .get(id.day, "inputs", part)
+                    .ok_or("no known answer")?;
+
+                check(&suite, id, part, &input, &SolveContext::default(), expected)
+            })
+            .with_ignored_flag(!known),
+        );
+
+        for example in examples
+            .iter()
+            .filter(|example| example.answer(part).is_some())
+        {
+            let suite = Arc::<Suite>::clone(suite);
+            let example = Arc::<Example>::clone(example);
+
+            trials.push(Trial::test(name(id, part, &example.set), move || {
+                let input = suite.dirs.read(&example.path(id))?;
+                let expected = example.answer(part).ok_or("no known answer")?;
+
+                check(&suite, id, part, &input, &example.context(), expected)
+            }));
+        }
+    }
+
+    trials
+}
+
+/// The name of the test of `part` of the puzzle `id` against the input `set`, like `2022/day14/part_2/inputs`.
+fn name(id: PuzzleId, part: Part, set: &str) -> String {
+    format!("{}/day{}/part_{}/{}", id.year, id.day, part, set)
+}
+
+/// Solves `part` of the puzzle `id` against `input` in `context`, and compares it with `expected`.
+fn check(
+    suite: &Suite,
+    id: PuzzleId,
+    part: Part,
+    input: &str,
+    context: &SolveContext,
+    expected: &PartSolution,
+) -> Result<(), Failed> {
+    let solver = suite.registry.get(&id).ok_or("not registered")?;
+
+    context.check_params(&solver.params())?;
+
+    let outcome = solver.solve(id.day, input, &[part], context);
+
+    let parts = outcome
+        .parts
+        .map_err(|error| format!("cannot parse the input: {}", error))?;
+
+    let solution = parts
+        .into_iter()
+        .next()
+        .ok_or("the part was not solved")?
+        .solution
+        .map_err(|error| format!("cannot solve: {}", error))?;
+
+    if solution == *expected {
+        Ok(())
+    } else {
+        Err(format!("expected {}, got {}", expected, solution).into())
+    }
 }
@@ -31,3 +31,12 @@ grcov "${profraw_files[@]}" \
     --output-path ./reports/ \
     --output-type lcov,html,markdown \
     --source-dir .
+
+# the answers of every day, next to the coverage, from a single run of a plain release build: no instrumentation, and
+# no counting allocator slowing the parts down
+unset LLVM_PROFILE_FILE
+export RUSTFLAGS="${base_rustflags}"
+
+cargo run --release --bin aoc -- run --all --jobs "$(nproc)" \
+    --report ./reports/answers.json \
+    --report ./reports/answers.xml